@@ -29,6 +29,7 @@ export class Bridge<C extends WasmBaseInstance> implements BridgeBase<C> {
 
   #cachegetInt32: Int32Array | null;
   #cachegetUint8: Uint8Array | null;
+  #cachegetUint32: Uint32Array | null;
   #heapNext: number;
   #wasm: C | null;
   #wasmError: string | null;
@@ -39,6 +40,7 @@ export class Bridge<C extends WasmBaseInstance> implements BridgeBase<C> {
     this.#createWasm = createWasm;
     this.#cachegetInt32 = null;
     this.#cachegetUint8 = null;
+    this.#cachegetUint32 = null;
     this.#heap = new Array(32)
       .fill(undefined)
       .concat(undefined, null, true, false);
@@ -154,6 +156,18 @@ export class Bridge<C extends WasmBaseInstance> implements BridgeBase<C> {
     return this.#cachegetUint8;
   }
 
+  /**
+   * @internal
+   * @description Retrieve an Uint32Array in the WASM interface
+   */
+  getUint32 (): Uint32Array {
+    if (this.#cachegetUint32 === null || this.#cachegetUint32.buffer !== this.#wasm!.memory.buffer) {
+      this.#cachegetUint32 = new Uint32Array(this.#wasm!.memory.buffer);
+    }
+
+    return this.#cachegetUint32;
+  }
+
   /**
    * @internal
    * @description Retrieves an Uint8Array in the WASM interface
@@ -182,6 +196,18 @@ export class Bridge<C extends WasmBaseInstance> implements BridgeBase<C> {
     return [ptr, arg.length];
   }
 
+  /**
+   * @internal
+   * @description Allocates an Uint32Array in the WASM interface
+   */
+  allocU32a (arg: Uint32Array): [number, number] {
+    const ptr = this.#wasm!.__wbindgen_malloc(arg.length * 4);
+
+    this.getUint32().set(arg, ptr / 4);
+
+    return [ptr, arg.length];
+  }
+
   /**
    * @internal
    * @description Allocates a string in the WASM interface
@@ -27,9 +27,11 @@ export interface BridgeBase<C extends WasmBaseInstance> extends InitResult<C> {
   addObject (obj: unknown): number;
   getInt32 (): Int32Array;
   getUint8 (): Uint8Array;
+  getUint32 (): Uint32Array;
   getU8a (ptr: number, len: number): Uint8Array;
   getString (ptr: number, len: number): string;
   allocU8a (arg: Uint8Array): [number, number];
+  allocU32a (arg: Uint32Array): [number, number];
   allocString (arg: string): [number, number];
   resultU8a (): Uint8Array;
   resultString (): string;
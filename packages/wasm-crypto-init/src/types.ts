@@ -25,6 +25,34 @@ export interface WasmCryptoInstance extends WasmBaseInstance {
 
   ext_ed_verify(ptrSig: number, lenSig: number, ptrMsg: number, lenMsg: number, ptrPub: number, lenPub: number): number;
 
+  ext_frost_dealer_keygen(resLen: 8, ptrSec: number, lenSec: number, max: number, min: number): void;
+
+  ext_frost_vss_verify(identifier: number, ptrShare: number, lenShare: number, ptrCommitment: number, lenCommitment: number): number;
+
+  ext_frost_pubkey_share(resLen: 8, identifier: number, ptrCommitment: number, lenCommitment: number): void;
+
+  ext_frost_verify_share(identifier: number, ptrPubShare: number, lenPubShare: number, ptrSigShare: number, lenSigShare: number, ptrMsg: number, lenMsg: number, ptrCommitments: number, lenCommitments: number, ptrPub: number, lenPub: number): number;
+
+  ext_frost_aggregate(resLen: 8, ptrMsg: number, lenMsg: number, ptrCommitments: number, lenCommitments: number, ptrSigShares: number, lenSigShares: number, ptrPub: number, lenPub: number): void;
+
+  ext_frost_dkg_new(identifier: number, max: number, min: number): number;
+
+  ext_frost_dkg_package(resLen: 8, handle: number): void;
+
+  ext_frost_dkg_share(resLen: 8, handle: number, identifier: number): void;
+
+  ext_frost_dkg_finish(resLen: 8, handle: number, ptrPackages: number, lenPackages: number, ptrShares: number, lenShares: number): void;
+
+  ext_frost_dkg_free(handle: number): number;
+
+  ext_frost_signer_new(identifier: number, ptrShare: number, lenShare: number, ptrPub: number, lenPub: number): number;
+
+  ext_frost_signer_commit(resLen: 8, handle: number): void;
+
+  ext_frost_signer_sign(resLen: 8, handle: number, ptrMsg: number, lenMsg: number, ptrCommitments: number, lenCommitments: number): void;
+
+  ext_frost_signer_free(handle: number): number;
+
   ext_dilithium_from_seed(resLen: 8, ptrSeed: number, lenSeed: number): void;
 
   ext_dilithium_sign(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number, ptrMsg: number, lenMsg: number): void;
@@ -39,6 +67,28 @@ export interface WasmCryptoInstance extends WasmBaseInstance {
 
   ext_blake2b(resLen: 8, ptrData: number, lenData: number, ptrKey: number, lenKey: number, size: number): void;
 
+  ext_blake2b_params(resLen: 8, ptrData: number, lenData: number, ptrKey: number, lenKey: number, ptrSalt: number, lenSalt: number, ptrPersonal: number, lenPersonal: number, size: number): void;
+
+  ext_blake2s(resLen: 8, ptrData: number, lenData: number, ptrKey: number, lenKey: number, size: number): void;
+
+  ext_blake2s_params(resLen: 8, ptrData: number, lenData: number, ptrKey: number, lenKey: number, ptrSalt: number, lenSalt: number, ptrPersonal: number, lenPersonal: number, size: number): void;
+
+  ext_blake3(resLen: 8, ptrData: number, lenData: number, ptrKey: number, lenKey: number, size: number): void;
+
+  ext_blake3_derive_key(resLen: 8, ptrCtx: number, lenCtx: number, ptrMaterial: number, lenMaterial: number, size: number): void;
+
+  ext_hkdf_sha256(resLen: 8, ptrSalt: number, lenSalt: number, ptrIkm: number, lenIkm: number, ptrInfo: number, lenInfo: number, size: number): void;
+
+  ext_hkdf_sha256_extract(resLen: 8, ptrSalt: number, lenSalt: number, ptrIkm: number, lenIkm: number): void;
+
+  ext_hkdf_sha256_expand(resLen: 8, ptrPrk: number, lenPrk: number, ptrInfo: number, lenInfo: number, size: number): void;
+
+  ext_hkdf_sha512(resLen: 8, ptrSalt: number, lenSalt: number, ptrIkm: number, lenIkm: number, ptrInfo: number, lenInfo: number, size: number): void;
+
+  ext_hkdf_sha512_extract(resLen: 8, ptrSalt: number, lenSalt: number, ptrIkm: number, lenIkm: number): void;
+
+  ext_hkdf_sha512_expand(resLen: 8, ptrPrk: number, lenPrk: number, ptrInfo: number, lenInfo: number, size: number): void;
+
   ext_hmac_sha256(resLen: 8, ptrKey: number, lenKey: number, ptrData: number, lenData: number): void;
 
   ext_hmac_sha512(resLen: 8, ptrKey: number, lenKey: number, ptrData: number, lenData: number): void;
@@ -47,16 +97,120 @@ export interface WasmCryptoInstance extends WasmBaseInstance {
 
   ext_keccak512(resLen: 8, ptrData: number, lenData: number): void;
 
+  ext_sha3_224(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_sha3_256(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_sha3_384(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_sha3_512(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_shake128(resLen: 8, ptrData: number, lenData: number, size: number): void;
+
+  ext_shake256(resLen: 8, ptrData: number, lenData: number, size: number): void;
+
+  ext_cshake128(resLen: 8, ptrData: number, lenData: number, ptrName: number, lenName: number, ptrCustom: number, lenCustom: number, size: number): void;
+
+  ext_cshake256(resLen: 8, ptrData: number, lenData: number, ptrName: number, lenName: number, ptrCustom: number, lenCustom: number, size: number): void;
+
+  ext_kmac128(resLen: 8, ptrKey: number, lenKey: number, ptrData: number, lenData: number, ptrCustom: number, lenCustom: number, size: number): void;
+
+  ext_kmac256(resLen: 8, ptrKey: number, lenKey: number, ptrData: number, lenData: number, ptrCustom: number, lenCustom: number, size: number): void;
+
   ext_pbkdf2(resLen: 8, ptrData: number, lenData: number, ptrSalt: number, lenSalt: number, rounds: number): void;
 
+  ext_pbkdf2_sha256(resLen: 8, ptrData: number, lenData: number, ptrSalt: number, lenSalt: number, rounds: number, size: number): void;
+
+  ext_pbkdf2_sha512(resLen: 8, ptrData: number, lenData: number, ptrSalt: number, lenSalt: number, rounds: number, size: number): void;
+
   ext_scrypt(resLen: 8, ptrPass: number, lenPass: number, ptrSalt: number, lenSalt: number, log2n: number, r: number, p: number): void;
 
+  ext_scrypt_size(resLen: 8, ptrPass: number, lenPass: number, ptrSalt: number, lenSalt: number, log2n: number, r: number, p: number, size: number): void;
+
   ext_sha256(resLen: 8, ptrData: number, lenData: number): void;
 
   ext_sha512(resLen: 8, ptrData: number, lenData: number): void;
 
+  ext_hash160(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_ripemd160(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_sha256d(resLen: 8, ptrData: number, lenData: number): void;
+
+  ext_tagged_hash(resLen: 8, ptrTag: number, lenTag: number, ptrData: number, lenData: number): void;
+
   ext_twox(resLen: 8, ptrData: number, lenData: number, rounds: number): void;
 
+  ext_xxh3_64(resLen: 8, ptrData: number, lenData: number, ptrSeed: number, lenSeed: number): void;
+
+  ext_xxh3_128(resLen: 8, ptrData: number, lenData: number, ptrSeed: number, lenSeed: number): void;
+
+  ext_xxh64(resLen: 8, ptrData: number, lenData: number, ptrSeed: number, lenSeed: number): void;
+
+  ext_hasher_update(hasher: number, ptrData: number, lenData: number): void;
+
+  ext_hasher_finalize(resLen: 8, hasher: number): void;
+
+  ext_hasher_free(hasher: number): number;
+
+  ext_blake2b_hasher_new(ptrKey: number, lenKey: number, size: number): number;
+
+  ext_blake2b_params_hasher_new(ptrKey: number, lenKey: number, ptrSalt: number, lenSalt: number, ptrPersonal: number, lenPersonal: number, size: number): number;
+
+  ext_blake2s_hasher_new(ptrKey: number, lenKey: number, size: number): number;
+
+  ext_blake2s_params_hasher_new(ptrKey: number, lenKey: number, ptrSalt: number, lenSalt: number, ptrPersonal: number, lenPersonal: number, size: number): number;
+
+  ext_blake3_hasher_new(ptrKey: number, lenKey: number, size: number): number;
+
+  ext_blake3_derive_key_hasher_new(ptrContext: number, lenContext: number, size: number): number;
+
+  ext_keccak256_hasher_new(): number;
+
+  ext_keccak512_hasher_new(): number;
+
+  ext_sha3_224_hasher_new(): number;
+
+  ext_sha3_256_hasher_new(): number;
+
+  ext_sha3_384_hasher_new(): number;
+
+  ext_sha3_512_hasher_new(): number;
+
+  ext_shake128_hasher_new(size: number): number;
+
+  ext_shake256_hasher_new(size: number): number;
+
+  ext_cshake128_hasher_new(ptrName: number, lenName: number, ptrCustom: number, lenCustom: number, size: number): number;
+
+  ext_cshake256_hasher_new(ptrName: number, lenName: number, ptrCustom: number, lenCustom: number, size: number): number;
+
+  ext_kmac128_hasher_new(ptrKey: number, lenKey: number, ptrCustom: number, lenCustom: number, size: number): number;
+
+  ext_kmac256_hasher_new(ptrKey: number, lenKey: number, ptrCustom: number, lenCustom: number, size: number): number;
+
+  ext_sha256_hasher_new(): number;
+
+  ext_sha512_hasher_new(): number;
+
+  ext_hash160_hasher_new(): number;
+
+  ext_ripemd160_hasher_new(): number;
+
+  ext_sha256d_hasher_new(): number;
+
+  ext_tagged_hasher_new(ptrTag: number, lenTag: number): number;
+
+  ext_twox_hasher_new(rounds: number): number;
+
+  ext_xxh3_hasher_new(ptrSeed: number, lenSeed: number, size: number): number;
+
+  ext_xxh64_hasher_new(ptrSeed: number, lenSeed: number): number;
+
+  ext_storage_hash(resLen: 8, hasher: number, ptrData: number, lenData: number): void;
+
+  ext_storage_key(resLen: 8, ptrPallet: number, lenPallet: number, ptrItem: number, lenItem: number, ptrHashers: number, lenHashers: number, ptrKeys: number, lenKeys: number, ptrLengths: number, lenLengths: number): void;
+
   ext_secp_from_seed(reslen: 8, ptrSec: number, lenSec: number): void;
 
   ext_secp_pub_compress(resLen: 8, ptrPub: number, lenPub: number): void;
@@ -67,6 +221,84 @@ export interface WasmCryptoInstance extends WasmBaseInstance {
 
   ext_secp_sign(resLen: 8, ptrMsgHash: number, lenMsgHash: number, ptrSec: number, lenSec: number): void;
 
+  ext_secp_verify(ptrMsgHash: number, lenMsgHash: number, ptrSig: number, lenSig: number, ptrPub: number, lenPub: number, strict: number): number;
+
+  ext_secp_verify_der(ptrMsgHash: number, lenMsgHash: number, ptrSig: number, lenSig: number, ptrPub: number, lenPub: number, strict: number): number;
+
+  ext_secp_sig_to_der(resLen: 8, ptrSig: number, lenSig: number): void;
+
+  ext_secp_sig_from_der(resLen: 8, ptrSig: number, lenSig: number): void;
+
+  ext_secp_sig_normalize(resLen: 8, ptrSig: number, lenSig: number): void;
+
+  ext_secp_pub_xonly(resLen: 8, ptrPub: number, lenPub: number): void;
+
+  ext_secp_schnorr_sign(resLen: 8, ptrMsgHash: number, lenMsgHash: number, ptrSec: number, lenSec: number, ptrAux: number, lenAux: number): void;
+
+  ext_secp_schnorr_verify(ptrSig: number, lenSig: number, ptrMsgHash: number, lenMsgHash: number, ptrPub: number, lenPub: number): number;
+
+  ext_secp_taproot_tweak_pub(resLen: 8, ptrPub: number, lenPub: number, ptrRoot: number, lenRoot: number): void;
+
+  ext_secp_taproot_tweak_sec(resLen: 8, ptrSec: number, lenSec: number, ptrRoot: number, lenRoot: number): void;
+
+  ext_secp_ecdh(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number): void;
+
+  ext_secp_ecdh_raw(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number): void;
+
+  ext_secp_ecies_encrypt(resLen: 8, ptrPub: number, lenPub: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_secp_ecies_decrypt(resLen: 8, ptrSec: number, lenSec: number, ptrEncrypted: number, lenEncrypted: number): void;
+
+  ext_secp_sec_tweak_add(resLen: 8, ptrSec: number, lenSec: number, ptrTweak: number, lenTweak: number): void;
+
+  ext_secp_sec_tweak_mul(resLen: 8, ptrSec: number, lenSec: number, ptrTweak: number, lenTweak: number): void;
+
+  ext_secp_sec_negate(resLen: 8, ptrSec: number, lenSec: number): void;
+
+  ext_secp_pub_tweak_add(resLen: 8, ptrPub: number, lenPub: number, ptrTweak: number, lenTweak: number): void;
+
+  ext_secp_pub_tweak_mul(resLen: 8, ptrPub: number, lenPub: number, ptrTweak: number, lenTweak: number): void;
+
+  ext_secp_pub_combine(resLen: 8, ptrPubs: number, lenPubs: number): void;
+
+  ext_secp_sign_hashed(resLen: 8, ptrMsg: number, lenMsg: number, ptrSec: number, lenSec: number, hasher: number, ptrExtra: number, lenExtra: number): void;
+
+  ext_secp_substrate_verify(ptrSig: number, lenSig: number, ptrMsg: number, lenMsg: number, ptrPub: number, lenPub: number): number;
+
+  ext_musig2_key_sort(resLen: 8, ptrPubs: number, lenPubs: number): void;
+
+  ext_musig2_nonce_agg(resLen: 8, ptrNonces: number, lenNonces: number): void;
+
+  ext_musig2_session_new(ptrPubs: number, lenPubs: number): number;
+
+  ext_musig2_session_tweak(resLen: 8, handle: number, ptrTweak: number, lenTweak: number, xonly: number): void;
+
+  ext_musig2_session_agg_pubkey(resLen: 8, handle: number): void;
+
+  ext_musig2_session_agg_pubkey_full(resLen: 8, handle: number): void;
+
+  ext_musig2_session_nonce_gen(resLen: 8, handle: number, ptrSec: number, lenSec: number, ptrMsg: number, lenMsg: number, ptrExtra: number, lenExtra: number): void;
+
+  ext_musig2_session_sign(resLen: 8, handle: number, ptrSec: number, lenSec: number, ptrAggNonce: number, lenAggNonce: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_musig2_session_partial_verify(handle: number, ptrPsig: number, lenPsig: number, ptrPubNonce: number, lenPubNonce: number, ptrPub: number, lenPub: number, ptrAggNonce: number, lenAggNonce: number, ptrMsg: number, lenMsg: number): number;
+
+  ext_musig2_session_aggregate(resLen: 8, handle: number, ptrPsigs: number, lenPsigs: number, ptrAggNonce: number, lenAggNonce: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_musig2_session_free(handle: number): number;
+
+  ext_eth_address(resLen: 8, ptrPub: number, lenPub: number): void;
+
+  ext_eth_address_checksum(resLen: 8, ptrAddress: number, lenAddress: number): void;
+
+  ext_eth_address_validate(ptrAddress: number, lenAddress: number): number;
+
+  ext_eth_personal_sign(resLen: 8, ptrMsg: number, lenMsg: number, ptrSec: number, lenSec: number): void;
+
+  ext_eth_personal_recover(resLen: 8, ptrMsg: number, lenMsg: number, ptrSig: number, lenSig: number): void;
+
+  ext_eth_typed_data_hash(resLen: 8, ptrJson: number, lenJson: number): void;
+
   ext_sr_derive_keypair_hard(resLen: 8, ptrPair: number, lenPair: number, ptrCc: number, lenCc: number): void;
 
   ext_sr_derive_keypair_soft(resLen: 8, ptrPub: number, lenPub: number, ptrCc: number, lenCc: number): void;
@@ -77,11 +309,131 @@ export interface WasmCryptoInstance extends WasmBaseInstance {
 
   ext_sr_sign(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number, ptrMsg: number, lenMsg: number): void;
 
+  ext_sr_sign_ctx(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_sr_verify_ctx(ptrSig: number, lenSig: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrPub: number, lenPub: number): number;
+
+  ext_sr_transcript_new(ptrLabel: number, lenLabel: number): number;
+
+  ext_sr_transcript_signing_context(ptrCtx: number, lenCtx: number): number;
+
+  ext_sr_transcript_bytes(transcript: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_sr_transcript_append_message(transcript: number, ptrLabel: number, lenLabel: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_sr_transcript_append_u64(transcript: number, ptrLabel: number, lenLabel: number, ptrValue: number, lenValue: number): void;
+
+  ext_sr_transcript_free(transcript: number): number;
+
+  ext_sr_sign_transcript(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number, transcript: number): void;
+
+  ext_sr_verify_transcript(ptrSig: number, lenSig: number, transcript: number, ptrPub: number, lenPub: number): number;
+
   ext_sr_verify(ptrSig: number, lenSig: number, ptrMsg: number, lenMsg: number, ptrPub: number, lenPub: number): number;
 
   ext_sr_agree(resLen: 8, ptrPub: number, lenPub: number, ptrSec: number, lenSec: number): void;
 
+  ext_sr_musig_pubkey(resLen: 8, ptrPubs: number, lenPubs: number): void;
+
+  ext_sr_musig_session_new(ptrPair: number, lenPair: number, ptrMsg: number, lenMsg: number): number;
+
+  ext_sr_musig_session_commitment(resLen: 8, handle: number): void;
+
+  ext_sr_musig_session_add_commitment(handle: number, ptrPub: number, lenPub: number, ptrCommitment: number, lenCommitment: number): void;
+
+  ext_sr_musig_session_reveal(resLen: 8, handle: number): void;
+
+  ext_sr_musig_session_add_reveal(handle: number, ptrPub: number, lenPub: number, ptrReveal: number, lenReveal: number): void;
+
+  ext_sr_musig_session_cosign(resLen: 8, handle: number): void;
+
+  ext_sr_musig_session_add_cosignature(handle: number, ptrPub: number, lenPub: number, ptrCosignature: number, lenCosignature: number): void;
+
+  ext_sr_musig_session_pubkey(resLen: 8, handle: number): void;
+
+  ext_sr_musig_session_sign(resLen: 8, handle: number): void;
+
+  ext_sr_musig_session_free(handle: number): number;
+
   ext_vrf_sign(resLen: 8, ptrSec: number, lenSec: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrExtra: number, lenExtra: number): void;
 
+  ext_vrf_sign_deterministic(resLen: 8, ptrSec: number, lenSec: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrExtra: number, lenExtra: number, ptrRand: number, lenRand: number): void;
+
   ext_vrf_verify(ptrPub: number, lenPub: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrExtra: number, lenExtra: number, ptrProof: number, lenProof: number): number;
+
+  ext_vrf_make_bytes(resLen: 8, ptrPub: number, lenPub: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrOut: number, lenOut: number, ptrBytesCtx: number, lenBytesCtx: number, size: number): void;
+
+  ext_vrf_check_threshold(ptrPub: number, lenPub: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrOut: number, lenOut: number, ptrBytesCtx: number, lenBytesCtx: number, ptrThreshold: number, lenThreshold: number): number;
+
+  ext_vrf_sign_transcript(resLen: 8, ptrSec: number, lenSec: number, transcript: number, ptrExtra: number, lenExtra: number): void;
+
+  ext_vrf_sign_transcript_deterministic(resLen: 8, ptrSec: number, lenSec: number, transcript: number, ptrExtra: number, lenExtra: number, ptrRand: number, lenRand: number): void;
+
+  ext_vrf_verify_transcript(ptrPub: number, lenPub: number, transcript: number, ptrExtra: number, lenExtra: number, ptrProof: number, lenProof: number): number;
+
+  ext_vrf_make_bytes_transcript(resLen: 8, ptrPub: number, lenPub: number, transcript: number, ptrOut: number, lenOut: number, ptrBytesCtx: number, lenBytesCtx: number, size: number): void;
+
+  ext_vrf_check_threshold_transcript(ptrPub: number, lenPub: number, transcript: number, ptrOut: number, lenOut: number, ptrBytesCtx: number, lenBytesCtx: number, ptrThreshold: number, lenThreshold: number): number;
+
+  ext_vrf_signer_new(ptrSec: number, lenSec: number): number;
+
+  ext_vrf_signer_push(signer: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_vrf_signer_push_transcript(signer: number, transcript: number): void;
+
+  ext_vrf_signer_sign(resLen: 8, signer: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number, ptrExtra: number, lenExtra: number): void;
+
+  ext_vrf_signer_sign_n(resLen: 8, signer: number, ptrExtra: number, lenExtra: number): void;
+
+  ext_vrf_signer_free(signer: number): number;
+
+  ext_vrf_verifier_new(ptrPub: number, lenPub: number): number;
+
+  ext_vrf_verifier_push(verifier: number, ptrCtx: number, lenCtx: number, ptrMsg: number, lenMsg: number): void;
+
+  ext_vrf_verifier_push_transcript(verifier: number, transcript: number): void;
+
+  ext_vrf_verifier_verify_n(verifier: number, ptrExtra: number, lenExtra: number, ptrProof: number, lenProof: number): number;
+
+  ext_vrf_verifier_free(verifier: number): number;
+
+  ext_babe_primary_threshold(resLen: 8, ptrCNumer: number, lenCNumer: number, ptrCDenom: number, lenCDenom: number, ptrWeight: number, lenWeight: number, ptrTotalWeight: number, lenTotalWeight: number): void;
+
+  ext_babe_secondary_author(ptrRand: number, lenRand: number, ptrSlot: number, lenSlot: number, authorities: number): number;
+
+  ext_babe_vrf_sign(resLen: 8, ptrSec: number, lenSec: number, ptrRand: number, lenRand: number, ptrSlot: number, lenSlot: number, ptrEpoch: number, lenEpoch: number): void;
+
+  ext_babe_vrf_verify(ptrPub: number, lenPub: number, ptrRand: number, lenRand: number, ptrSlot: number, lenSlot: number, ptrEpoch: number, lenEpoch: number, ptrProof: number, lenProof: number): number;
+
+  ext_babe_claim_slot(ptrPub: number, lenPub: number, authorityIndex: number, authorities: number, ptrRand: number, lenRand: number, ptrSlot: number, lenSlot: number, ptrEpoch: number, lenEpoch: number, ptrProof: number, lenProof: number, ptrThreshold: number, lenThreshold: number): number;
+
+  ext_bandersnatch_from_seed(resLen: 8, ptrSeed: number, lenSeed: number): void;
+
+  ext_bandersnatch_public(resLen: 8, ptrSecret: number, lenSecret: number): void;
+
+  ext_bandersnatch_vrf_output(resLen: 8, ptrSecret: number, lenSecret: number, ptrInput: number, lenInput: number): void;
+
+  ext_bandersnatch_output_hash(resLen: 8, ptrOutput: number, lenOutput: number): void;
+
+  ext_bandersnatch_ietf_sign(resLen: 8, ptrSecret: number, lenSecret: number, ptrInput: number, lenInput: number, ptrAd: number, lenAd: number): void;
+
+  ext_bandersnatch_ietf_verify(ptrPub: number, lenPub: number, ptrInput: number, lenInput: number, ptrAd: number, lenAd: number, ptrSig: number, lenSig: number): number;
+
+  ext_bandersnatch_ring_new(ptrSrs: number, lenSrs: number, ringSize: number): number;
+
+  ext_bandersnatch_ring_max_size(ring: number): number;
+
+  ext_bandersnatch_ring_commitment(resLen: 8, ring: number, ptrPubs: number, lenPubs: number): void;
+
+  ext_bandersnatch_ring_sign(resLen: 8, ring: number, ptrSecret: number, lenSecret: number, ptrPubs: number, lenPubs: number, index: number, ptrInput: number, lenInput: number, ptrAd: number, lenAd: number): void;
+
+  ext_bandersnatch_ring_verify(ring: number, ptrSig: number, lenSig: number, ptrCommitment: number, lenCommitment: number, ptrInput: number, lenInput: number, ptrAd: number, lenAd: number): number;
+
+  ext_bandersnatch_ring_free(ring: number): number;
+
+  ext_argon2d(resLen: 8, ptrPass: number, lenPass: number, ptrSalt: number, lenSalt: number, memory: number, iterations: number, parallelism: number, size: number): void;
+
+  ext_argon2i(resLen: 8, ptrPass: number, lenPass: number, ptrSalt: number, lenSalt: number, memory: number, iterations: number, parallelism: number, size: number): void;
+
+  ext_argon2id(resLen: 8, ptrPass: number, lenPass: number, ptrSalt: number, lenSalt: number, memory: number, iterations: number, parallelism: number, size: number): void;
 }
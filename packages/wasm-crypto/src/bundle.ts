@@ -88,6 +88,86 @@ export const ed25519Verify = /*#__PURE__*/ withWasm((wasm, signature: Uint8Array
   return ret !== 0;
 });
 
+export const frostDealerKeygen = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, max: number, min: number): Uint8Array => {
+  wasm.ext_frost_dealer_keygen(8, ...bridge.allocU8a(secret), max, min);
+
+  return bridge.resultU8a();
+});
+
+export const frostVssVerify = /*#__PURE__*/ withWasm((wasm, identifier: number, share: Uint8Array, commitment: Uint8Array): boolean => {
+  const ret = wasm.ext_frost_vss_verify(identifier, ...bridge.allocU8a(share), ...bridge.allocU8a(commitment));
+
+  return ret !== 0;
+});
+
+export const frostPubkeyShare = /*#__PURE__*/ withWasm((wasm, identifier: number, commitment: Uint8Array): Uint8Array => {
+  wasm.ext_frost_pubkey_share(8, identifier, ...bridge.allocU8a(commitment));
+
+  return bridge.resultU8a();
+});
+
+export const frostVerifyShare = /*#__PURE__*/ withWasm((wasm, identifier: number, pubkeyShare: Uint8Array, sigShare: Uint8Array, message: Uint8Array, commitments: Uint8Array, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_frost_verify_share(identifier, ...bridge.allocU8a(pubkeyShare), ...bridge.allocU8a(sigShare), ...bridge.allocU8a(message), ...bridge.allocU8a(commitments), ...bridge.allocU8a(pubkey));
+
+  return ret !== 0;
+});
+
+export const frostAggregate = /*#__PURE__*/ withWasm((wasm, message: Uint8Array, commitments: Uint8Array, sigShares: Uint8Array, pubkey: Uint8Array): Uint8Array => {
+  wasm.ext_frost_aggregate(8, ...bridge.allocU8a(message), ...bridge.allocU8a(commitments), ...bridge.allocU8a(sigShares), ...bridge.allocU8a(pubkey));
+
+  return bridge.resultU8a();
+});
+
+export const frostDkgNew = /*#__PURE__*/ withWasm((wasm, identifier: number, max: number, min: number): number => {
+  return wasm.ext_frost_dkg_new(identifier, max, min);
+});
+
+export const frostDkgPackage = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_frost_dkg_package(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const frostDkgShare = /*#__PURE__*/ withWasm((wasm, handle: number, identifier: number): Uint8Array => {
+  wasm.ext_frost_dkg_share(8, handle, identifier);
+
+  return bridge.resultU8a();
+});
+
+export const frostDkgFinish = /*#__PURE__*/ withWasm((wasm, handle: number, packages: Uint8Array, shares: Uint8Array): Uint8Array => {
+  wasm.ext_frost_dkg_finish(8, handle, ...bridge.allocU8a(packages), ...bridge.allocU8a(shares));
+
+  return bridge.resultU8a();
+});
+
+export const frostDkgFree = /*#__PURE__*/ withWasm((wasm, handle: number): boolean => {
+  const ret = wasm.ext_frost_dkg_free(handle);
+
+  return ret !== 0;
+});
+
+export const frostSignerNew = /*#__PURE__*/ withWasm((wasm, identifier: number, share: Uint8Array, pubkey: Uint8Array): number => {
+  return wasm.ext_frost_signer_new(identifier, ...bridge.allocU8a(share), ...bridge.allocU8a(pubkey));
+});
+
+export const frostSignerCommit = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_frost_signer_commit(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const frostSignerSign = /*#__PURE__*/ withWasm((wasm, handle: number, message: Uint8Array, commitments: Uint8Array): Uint8Array => {
+  wasm.ext_frost_signer_sign(8, handle, ...bridge.allocU8a(message), ...bridge.allocU8a(commitments));
+
+  return bridge.resultU8a();
+});
+
+export const frostSignerFree = /*#__PURE__*/ withWasm((wasm, handle: number): boolean => {
+  const ret = wasm.ext_frost_signer_free(handle);
+
+  return ret !== 0;
+});
+
 export const dilithium2KeypairFromSeed = /*#__PURE__*/ withWasm((wasm, seed: Uint8Array): Uint8Array => {
   wasm.ext_dilithium_from_seed(8, ...bridge.allocU8a(seed));
 
@@ -154,6 +234,238 @@ export const secp256k1Sign = /*#__PURE__*/ withWasm((wasm, msgHash: Uint8Array,
   return bridge.resultU8a();
 });
 
+export const secp256k1Verify = /*#__PURE__*/ withWasm((wasm, msgHash: Uint8Array, sig: Uint8Array, pubkey: Uint8Array, strict: boolean): boolean => {
+  const ret = wasm.ext_secp_verify(...bridge.allocU8a(msgHash), ...bridge.allocU8a(sig), ...bridge.allocU8a(pubkey), strict ? 1 : 0);
+
+  return ret !== 0;
+});
+
+export const secp256k1VerifyDer = /*#__PURE__*/ withWasm((wasm, msgHash: Uint8Array, sig: Uint8Array, pubkey: Uint8Array, strict: boolean): boolean => {
+  const ret = wasm.ext_secp_verify_der(...bridge.allocU8a(msgHash), ...bridge.allocU8a(sig), ...bridge.allocU8a(pubkey), strict ? 1 : 0);
+
+  return ret !== 0;
+});
+
+export const secp256k1SigToDer = /*#__PURE__*/ withWasm((wasm, sig: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sig_to_der(8, ...bridge.allocU8a(sig));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SigFromDer = /*#__PURE__*/ withWasm((wasm, sig: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sig_from_der(8, ...bridge.allocU8a(sig));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SigNormalize = /*#__PURE__*/ withWasm((wasm, sig: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sig_normalize(8, ...bridge.allocU8a(sig));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1PubXonly = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array): Uint8Array => {
+  wasm.ext_secp_pub_xonly(8, ...bridge.allocU8a(pubkey));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SchnorrSign = /*#__PURE__*/ withWasm((wasm, msgHash: Uint8Array, seckey: Uint8Array, aux: Uint8Array): Uint8Array => {
+  wasm.ext_secp_schnorr_sign(8, ...bridge.allocU8a(msgHash), ...bridge.allocU8a(seckey), ...bridge.allocU8a(aux));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SchnorrVerify = /*#__PURE__*/ withWasm((wasm, sig: Uint8Array, msgHash: Uint8Array, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_secp_schnorr_verify(...bridge.allocU8a(sig), ...bridge.allocU8a(msgHash), ...bridge.allocU8a(pubkey));
+
+  return ret !== 0;
+});
+
+export const secp256k1TaprootTweakPub = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, merkleRoot: Uint8Array): Uint8Array => {
+  wasm.ext_secp_taproot_tweak_pub(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(merkleRoot));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1TaprootTweakSec = /*#__PURE__*/ withWasm((wasm, seckey: Uint8Array, merkleRoot: Uint8Array): Uint8Array => {
+  wasm.ext_secp_taproot_tweak_sec(8, ...bridge.allocU8a(seckey), ...bridge.allocU8a(merkleRoot));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1Ecdh = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, seckey: Uint8Array): Uint8Array => {
+  wasm.ext_secp_ecdh(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(seckey));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1EcdhRaw = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, seckey: Uint8Array): Uint8Array => {
+  wasm.ext_secp_ecdh_raw(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(seckey));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1EciesEncrypt = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, message: Uint8Array): Uint8Array => {
+  wasm.ext_secp_ecies_encrypt(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(message));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1EciesDecrypt = /*#__PURE__*/ withWasm((wasm, seckey: Uint8Array, encrypted: Uint8Array): Uint8Array => {
+  wasm.ext_secp_ecies_decrypt(8, ...bridge.allocU8a(seckey), ...bridge.allocU8a(encrypted));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SecTweakAdd = /*#__PURE__*/ withWasm((wasm, seckey: Uint8Array, tweak: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sec_tweak_add(8, ...bridge.allocU8a(seckey), ...bridge.allocU8a(tweak));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SecTweakMul = /*#__PURE__*/ withWasm((wasm, seckey: Uint8Array, tweak: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sec_tweak_mul(8, ...bridge.allocU8a(seckey), ...bridge.allocU8a(tweak));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SecNegate = /*#__PURE__*/ withWasm((wasm, seckey: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sec_negate(8, ...bridge.allocU8a(seckey));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1PubTweakAdd = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, tweak: Uint8Array): Uint8Array => {
+  wasm.ext_secp_pub_tweak_add(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(tweak));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1PubTweakMul = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, tweak: Uint8Array): Uint8Array => {
+  wasm.ext_secp_pub_tweak_mul(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(tweak));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1PubCombine = /*#__PURE__*/ withWasm((wasm, pubkeys: Uint8Array): Uint8Array => {
+  wasm.ext_secp_pub_combine(8, ...bridge.allocU8a(pubkeys));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SignHashed = /*#__PURE__*/ withWasm((wasm, message: Uint8Array, seckey: Uint8Array, hasher: number, extra: Uint8Array): Uint8Array => {
+  wasm.ext_secp_sign_hashed(8, ...bridge.allocU8a(message), ...bridge.allocU8a(seckey), hasher, ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const secp256k1SubstrateVerify = /*#__PURE__*/ withWasm((wasm, sig: Uint8Array, message: Uint8Array, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_secp_substrate_verify(...bridge.allocU8a(sig), ...bridge.allocU8a(message), ...bridge.allocU8a(pubkey));
+
+  return ret !== 0;
+});
+
+export const musig2KeySort = /*#__PURE__*/ withWasm((wasm, pubkeys: Uint8Array): Uint8Array => {
+  wasm.ext_musig2_key_sort(8, ...bridge.allocU8a(pubkeys));
+
+  return bridge.resultU8a();
+});
+
+export const musig2NonceAgg = /*#__PURE__*/ withWasm((wasm, pubnonces: Uint8Array): Uint8Array => {
+  wasm.ext_musig2_nonce_agg(8, ...bridge.allocU8a(pubnonces));
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionNew = /*#__PURE__*/ withWasm((wasm, pubkeys: Uint8Array): number => {
+  return wasm.ext_musig2_session_new(...bridge.allocU8a(pubkeys));
+});
+
+export const musig2SessionTweak = /*#__PURE__*/ withWasm((wasm, handle: number, tweak: Uint8Array, xonly: boolean): Uint8Array => {
+  wasm.ext_musig2_session_tweak(8, handle, ...bridge.allocU8a(tweak), xonly ? 1 : 0);
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionAggPubkey = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_musig2_session_agg_pubkey(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionAggPubkeyFull = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_musig2_session_agg_pubkey_full(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionNonceGen = /*#__PURE__*/ withWasm((wasm, handle: number, seckey: Uint8Array, message: Uint8Array, extra: Uint8Array): Uint8Array => {
+  wasm.ext_musig2_session_nonce_gen(8, handle, ...bridge.allocU8a(seckey), ...bridge.allocU8a(message), ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionSign = /*#__PURE__*/ withWasm((wasm, handle: number, seckey: Uint8Array, aggnonce: Uint8Array, message: Uint8Array): Uint8Array => {
+  wasm.ext_musig2_session_sign(8, handle, ...bridge.allocU8a(seckey), ...bridge.allocU8a(aggnonce), ...bridge.allocU8a(message));
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionPartialVerify = /*#__PURE__*/ withWasm((wasm, handle: number, psig: Uint8Array, pubnonce: Uint8Array, pubkey: Uint8Array, aggnonce: Uint8Array, message: Uint8Array): boolean => {
+  const ret = wasm.ext_musig2_session_partial_verify(handle, ...bridge.allocU8a(psig), ...bridge.allocU8a(pubnonce), ...bridge.allocU8a(pubkey), ...bridge.allocU8a(aggnonce), ...bridge.allocU8a(message));
+
+  return ret !== 0;
+});
+
+export const musig2SessionAggregate = /*#__PURE__*/ withWasm((wasm, handle: number, psigs: Uint8Array, aggnonce: Uint8Array, message: Uint8Array): Uint8Array => {
+  wasm.ext_musig2_session_aggregate(8, handle, ...bridge.allocU8a(psigs), ...bridge.allocU8a(aggnonce), ...bridge.allocU8a(message));
+
+  return bridge.resultU8a();
+});
+
+export const musig2SessionFree = /*#__PURE__*/ withWasm((wasm, handle: number): boolean => {
+  const ret = wasm.ext_musig2_session_free(handle);
+
+  return ret !== 0;
+});
+
+export const ethAddress = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array): Uint8Array => {
+  wasm.ext_eth_address(8, ...bridge.allocU8a(pubkey));
+
+  return bridge.resultU8a();
+});
+
+export const ethAddressChecksum = /*#__PURE__*/ withWasm((wasm, address: Uint8Array): string => {
+  wasm.ext_eth_address_checksum(8, ...bridge.allocU8a(address));
+
+  return bridge.resultString();
+});
+
+export const ethAddressValidate = /*#__PURE__*/ withWasm((wasm, address: string): boolean => {
+  const ret = wasm.ext_eth_address_validate(...bridge.allocString(address));
+
+  return ret !== 0;
+});
+
+export const ethPersonalSign = /*#__PURE__*/ withWasm((wasm, message: Uint8Array, seckey: Uint8Array): Uint8Array => {
+  wasm.ext_eth_personal_sign(8, ...bridge.allocU8a(message), ...bridge.allocU8a(seckey));
+
+  return bridge.resultU8a();
+});
+
+export const ethPersonalRecover = /*#__PURE__*/ withWasm((wasm, message: Uint8Array, sig: Uint8Array): Uint8Array => {
+  wasm.ext_eth_personal_recover(8, ...bridge.allocU8a(message), ...bridge.allocU8a(sig));
+
+  return bridge.resultU8a();
+});
+
+export const ethTypedDataHash = /*#__PURE__*/ withWasm((wasm, json: string): Uint8Array => {
+  wasm.ext_eth_typed_data_hash(8, ...bridge.allocString(json));
+
+  return bridge.resultU8a();
+});
+
 export const sr25519DeriveKeypairHard = /*#__PURE__*/ withWasm((wasm, pair: Uint8Array, cc: Uint8Array): Uint8Array => {
   wasm.ext_sr_derive_keypair_hard(8, ...bridge.allocU8a(pair), ...bridge.allocU8a(cc));
 
@@ -184,80 +496,570 @@ export const sr25519Sign = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, sec
   return bridge.resultU8a();
 });
 
-export const sr25519Verify = /*#__PURE__*/ withWasm((wasm, signature: Uint8Array, message: Uint8Array, pubkey: Uint8Array): boolean => {
-  const ret = wasm.ext_sr_verify(...bridge.allocU8a(signature), ...bridge.allocU8a(message), ...bridge.allocU8a(pubkey));
+export const sr25519SignCtx = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, secret: Uint8Array, context: Uint8Array, message: Uint8Array): Uint8Array => {
+  wasm.ext_sr_sign_ctx(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(secret), ...bridge.allocU8a(context), ...bridge.allocU8a(message));
+
+  return bridge.resultU8a();
+});
+
+export const sr25519VerifyCtx = /*#__PURE__*/ withWasm((wasm, signature: Uint8Array, context: Uint8Array, message: Uint8Array, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_sr_verify_ctx(...bridge.allocU8a(signature), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(pubkey));
 
   return ret !== 0;
 });
 
-export const sr25519Agree = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, secret: Uint8Array): Uint8Array => {
-  wasm.ext_sr_agree(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(secret));
+export const sr25519TranscriptNew = /*#__PURE__*/ withWasm((wasm, label: Uint8Array): number => {
+  return wasm.ext_sr_transcript_new(...bridge.allocU8a(label));
+});
 
-  return bridge.resultU8a();
+export const sr25519TranscriptSigningContext = /*#__PURE__*/ withWasm((wasm, context: Uint8Array): number => {
+  return wasm.ext_sr_transcript_signing_context(...bridge.allocU8a(context));
 });
 
-export const vrfSign = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, context: Uint8Array, message: Uint8Array, extra: Uint8Array): Uint8Array => {
-  wasm.ext_vrf_sign(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra));
+export const sr25519TranscriptBytes = /*#__PURE__*/ withWasm((wasm, transcript: number, message: Uint8Array): void => {
+  wasm.ext_sr_transcript_bytes(transcript, ...bridge.allocU8a(message));
+});
 
-  return bridge.resultU8a();
+export const sr25519TranscriptAppendMessage = /*#__PURE__*/ withWasm((wasm, transcript: number, label: Uint8Array, message: Uint8Array): void => {
+  wasm.ext_sr_transcript_append_message(transcript, ...bridge.allocU8a(label), ...bridge.allocU8a(message));
 });
 
-export const vrfVerify = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, context: Uint8Array, message: Uint8Array, extra: Uint8Array, outAndProof: Uint8Array): boolean => {
-  const ret = wasm.ext_vrf_verify(...bridge.allocU8a(pubkey), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra), ...bridge.allocU8a(outAndProof));
+export const sr25519TranscriptAppendU64 = /*#__PURE__*/ withWasm((wasm, transcript: number, label: Uint8Array, value: Uint8Array): void => {
+  wasm.ext_sr_transcript_append_u64(transcript, ...bridge.allocU8a(label), ...bridge.allocU8a(value));
+});
+
+export const sr25519TranscriptFree = /*#__PURE__*/ withWasm((wasm, transcript: number): boolean => {
+  const ret = wasm.ext_sr_transcript_free(transcript);
 
   return ret !== 0;
 });
 
-export const blake2b = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, size: number): Uint8Array => {
-  wasm.ext_blake2b(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), size);
+export const sr25519SignTranscript = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, secret: Uint8Array, transcript: number): Uint8Array => {
+  wasm.ext_sr_sign_transcript(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(secret), transcript);
 
   return bridge.resultU8a();
 });
 
-export const hmacSha256 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array): Uint8Array => {
-  wasm.ext_hmac_sha256(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data));
+export const sr25519VerifyTranscript = /*#__PURE__*/ withWasm((wasm, signature: Uint8Array, transcript: number, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_sr_verify_transcript(...bridge.allocU8a(signature), transcript, ...bridge.allocU8a(pubkey));
 
-  return bridge.resultU8a();
+  return ret !== 0;
 });
 
-export const hmacSha512 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array): Uint8Array => {
-  wasm.ext_hmac_sha512(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data));
+export const sr25519Verify = /*#__PURE__*/ withWasm((wasm, signature: Uint8Array, message: Uint8Array, pubkey: Uint8Array): boolean => {
+  const ret = wasm.ext_sr_verify(...bridge.allocU8a(signature), ...bridge.allocU8a(message), ...bridge.allocU8a(pubkey));
 
-  return bridge.resultU8a();
+  return ret !== 0;
 });
 
-export const keccak256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
-  wasm.ext_keccak256(8, ...bridge.allocU8a(data));
+export const sr25519Agree = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, secret: Uint8Array): Uint8Array => {
+  wasm.ext_sr_agree(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(secret));
 
   return bridge.resultU8a();
 });
 
-export const keccak512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
-  wasm.ext_keccak512(8, ...bridge.allocU8a(data));
+export const sr25519MusigPubkey = /*#__PURE__*/ withWasm((wasm, pubkeys: Uint8Array): Uint8Array => {
+  wasm.ext_sr_musig_pubkey(8, ...bridge.allocU8a(pubkeys));
 
   return bridge.resultU8a();
 });
 
-export const pbkdf2 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, salt: Uint8Array, rounds: number): Uint8Array => {
-  wasm.ext_pbkdf2(8, ...bridge.allocU8a(data), ...bridge.allocU8a(salt), rounds);
-
-  return bridge.resultU8a();
+export const sr25519MusigSessionNew = /*#__PURE__*/ withWasm((wasm, pair: Uint8Array, message: Uint8Array): number => {
+  return wasm.ext_sr_musig_session_new(...bridge.allocU8a(pair), ...bridge.allocU8a(message));
 });
 
-export const scrypt = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, log2n: number, r: number, p: number): Uint8Array => {
-  wasm.ext_scrypt(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), log2n, r, p);
+export const sr25519MusigSessionCommitment = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_sr_musig_session_commitment(8, handle);
 
   return bridge.resultU8a();
 });
 
-export const sha256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
-  wasm.ext_sha256(8, ...bridge.allocU8a(data));
+export const sr25519MusigSessionAddCommitment = /*#__PURE__*/ withWasm((wasm, handle: number, pubkey: Uint8Array, commitment: Uint8Array): void => {
+  wasm.ext_sr_musig_session_add_commitment(handle, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(commitment));
+});
+
+export const sr25519MusigSessionReveal = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_sr_musig_session_reveal(8, handle);
 
   return bridge.resultU8a();
 });
 
-export const sha512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
-  wasm.ext_sha512(8, ...bridge.allocU8a(data));
+export const sr25519MusigSessionAddReveal = /*#__PURE__*/ withWasm((wasm, handle: number, pubkey: Uint8Array, reveal: Uint8Array): void => {
+  wasm.ext_sr_musig_session_add_reveal(handle, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(reveal));
+});
+
+export const sr25519MusigSessionCosign = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_sr_musig_session_cosign(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const sr25519MusigSessionAddCosignature = /*#__PURE__*/ withWasm((wasm, handle: number, pubkey: Uint8Array, cosignature: Uint8Array): void => {
+  wasm.ext_sr_musig_session_add_cosignature(handle, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(cosignature));
+});
+
+export const sr25519MusigSessionPubkey = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_sr_musig_session_pubkey(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const sr25519MusigSessionSign = /*#__PURE__*/ withWasm((wasm, handle: number): Uint8Array => {
+  wasm.ext_sr_musig_session_sign(8, handle);
+
+  return bridge.resultU8a();
+});
+
+export const sr25519MusigSessionFree = /*#__PURE__*/ withWasm((wasm, handle: number): boolean => {
+  const ret = wasm.ext_sr_musig_session_free(handle);
+
+  return ret !== 0;
+});
+
+export const vrfSign = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, context: Uint8Array, message: Uint8Array, extra: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_sign(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const vrfSignDeterministic = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, context: Uint8Array, message: Uint8Array, extra: Uint8Array, randomness: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_sign_deterministic(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra), ...bridge.allocU8a(randomness));
+
+  return bridge.resultU8a();
+});
+
+export const vrfVerify = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, context: Uint8Array, message: Uint8Array, extra: Uint8Array, outAndProof: Uint8Array): boolean => {
+  const ret = wasm.ext_vrf_verify(...bridge.allocU8a(pubkey), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra), ...bridge.allocU8a(outAndProof));
+
+  return ret !== 0;
+});
+
+export const vrfMakeBytes = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, context: Uint8Array, message: Uint8Array, out: Uint8Array, bytesContext: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_vrf_make_bytes(8, ...bridge.allocU8a(pubkey), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(out), ...bridge.allocU8a(bytesContext), size);
+
+  return bridge.resultU8a();
+});
+
+export const vrfCheckThreshold = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, context: Uint8Array, message: Uint8Array, out: Uint8Array, bytesContext: Uint8Array, threshold: Uint8Array): boolean => {
+  const ret = wasm.ext_vrf_check_threshold(...bridge.allocU8a(pubkey), ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(out), ...bridge.allocU8a(bytesContext), ...bridge.allocU8a(threshold));
+
+  return ret !== 0;
+});
+
+export const vrfSignTranscript = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, transcript: number, extra: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_sign_transcript(8, ...bridge.allocU8a(secret), transcript, ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const vrfSignTranscriptDeterministic = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, transcript: number, extra: Uint8Array, randomness: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_sign_transcript_deterministic(8, ...bridge.allocU8a(secret), transcript, ...bridge.allocU8a(extra), ...bridge.allocU8a(randomness));
+
+  return bridge.resultU8a();
+});
+
+export const vrfVerifyTranscript = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, transcript: number, extra: Uint8Array, outAndProof: Uint8Array): boolean => {
+  const ret = wasm.ext_vrf_verify_transcript(...bridge.allocU8a(pubkey), transcript, ...bridge.allocU8a(extra), ...bridge.allocU8a(outAndProof));
+
+  return ret !== 0;
+});
+
+export const vrfMakeBytesTranscript = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, transcript: number, out: Uint8Array, bytesContext: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_vrf_make_bytes_transcript(8, ...bridge.allocU8a(pubkey), transcript, ...bridge.allocU8a(out), ...bridge.allocU8a(bytesContext), size);
+
+  return bridge.resultU8a();
+});
+
+export const vrfCheckThresholdTranscript = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, transcript: number, out: Uint8Array, bytesContext: Uint8Array, threshold: Uint8Array): boolean => {
+  const ret = wasm.ext_vrf_check_threshold_transcript(...bridge.allocU8a(pubkey), transcript, ...bridge.allocU8a(out), ...bridge.allocU8a(bytesContext), ...bridge.allocU8a(threshold));
+
+  return ret !== 0;
+});
+
+export const vrfSignerNew = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array): number => {
+  return wasm.ext_vrf_signer_new(...bridge.allocU8a(secret));
+});
+
+export const vrfSignerPush = /*#__PURE__*/ withWasm((wasm, signer: number, context: Uint8Array, message: Uint8Array): void => {
+  wasm.ext_vrf_signer_push(signer, ...bridge.allocU8a(context), ...bridge.allocU8a(message));
+});
+
+export const vrfSignerPushTranscript = /*#__PURE__*/ withWasm((wasm, signer: number, transcript: number): void => {
+  wasm.ext_vrf_signer_push_transcript(signer, transcript);
+});
+
+export const vrfSignerSign = /*#__PURE__*/ withWasm((wasm, signer: number, context: Uint8Array, message: Uint8Array, extra: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_signer_sign(8, signer, ...bridge.allocU8a(context), ...bridge.allocU8a(message), ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const vrfSignerSignN = /*#__PURE__*/ withWasm((wasm, signer: number, extra: Uint8Array): Uint8Array => {
+  wasm.ext_vrf_signer_sign_n(8, signer, ...bridge.allocU8a(extra));
+
+  return bridge.resultU8a();
+});
+
+export const vrfSignerFree = /*#__PURE__*/ withWasm((wasm, signer: number): boolean => {
+  const ret = wasm.ext_vrf_signer_free(signer);
+
+  return ret !== 0;
+});
+
+export const vrfVerifierNew = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array): number => {
+  return wasm.ext_vrf_verifier_new(...bridge.allocU8a(pubkey));
+});
+
+export const vrfVerifierPush = /*#__PURE__*/ withWasm((wasm, verifier: number, context: Uint8Array, message: Uint8Array): void => {
+  wasm.ext_vrf_verifier_push(verifier, ...bridge.allocU8a(context), ...bridge.allocU8a(message));
+});
+
+export const vrfVerifierPushTranscript = /*#__PURE__*/ withWasm((wasm, verifier: number, transcript: number): void => {
+  wasm.ext_vrf_verifier_push_transcript(verifier, transcript);
+});
+
+export const vrfVerifierVerifyN = /*#__PURE__*/ withWasm((wasm, verifier: number, extra: Uint8Array, outAndProof: Uint8Array): boolean => {
+  const ret = wasm.ext_vrf_verifier_verify_n(verifier, ...bridge.allocU8a(extra), ...bridge.allocU8a(outAndProof));
+
+  return ret !== 0;
+});
+
+export const vrfVerifierFree = /*#__PURE__*/ withWasm((wasm, verifier: number): boolean => {
+  const ret = wasm.ext_vrf_verifier_free(verifier);
+
+  return ret !== 0;
+});
+
+export const babePrimaryThreshold = /*#__PURE__*/ withWasm((wasm, cNumer: Uint8Array, cDenom: Uint8Array, weight: Uint8Array, totalWeight: Uint8Array): Uint8Array => {
+  wasm.ext_babe_primary_threshold(8, ...bridge.allocU8a(cNumer), ...bridge.allocU8a(cDenom), ...bridge.allocU8a(weight), ...bridge.allocU8a(totalWeight));
+
+  return bridge.resultU8a();
+});
+
+export const babeSecondaryAuthor = /*#__PURE__*/ withWasm((wasm, randomness: Uint8Array, slot: Uint8Array, authorities: number): number => {
+  return wasm.ext_babe_secondary_author(...bridge.allocU8a(randomness), ...bridge.allocU8a(slot), authorities);
+});
+
+export const babeVrfSign = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, randomness: Uint8Array, slot: Uint8Array, epoch: Uint8Array): Uint8Array => {
+  wasm.ext_babe_vrf_sign(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(randomness), ...bridge.allocU8a(slot), ...bridge.allocU8a(epoch));
+
+  return bridge.resultU8a();
+});
+
+export const babeVrfVerify = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, randomness: Uint8Array, slot: Uint8Array, epoch: Uint8Array, outAndProof: Uint8Array): boolean => {
+  const ret = wasm.ext_babe_vrf_verify(...bridge.allocU8a(pubkey), ...bridge.allocU8a(randomness), ...bridge.allocU8a(slot), ...bridge.allocU8a(epoch), ...bridge.allocU8a(outAndProof));
+
+  return ret !== 0;
+});
+
+export const babeClaimSlot = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, authorityIndex: number, authorities: number, randomness: Uint8Array, slot: Uint8Array, epoch: Uint8Array, outAndProof: Uint8Array, threshold: Uint8Array): number => {
+  return wasm.ext_babe_claim_slot(...bridge.allocU8a(pubkey), authorityIndex, authorities, ...bridge.allocU8a(randomness), ...bridge.allocU8a(slot), ...bridge.allocU8a(epoch), ...bridge.allocU8a(outAndProof), ...bridge.allocU8a(threshold));
+});
+
+export const bandersnatchFromSeed = /*#__PURE__*/ withWasm((wasm, seed: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_from_seed(8, ...bridge.allocU8a(seed));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchPublic = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_public(8, ...bridge.allocU8a(secret));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchVrfOutput = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, input: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_vrf_output(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(input));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchOutputHash = /*#__PURE__*/ withWasm((wasm, output: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_output_hash(8, ...bridge.allocU8a(output));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchIetfSign = /*#__PURE__*/ withWasm((wasm, secret: Uint8Array, input: Uint8Array, ad: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_ietf_sign(8, ...bridge.allocU8a(secret), ...bridge.allocU8a(input), ...bridge.allocU8a(ad));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchIetfVerify = /*#__PURE__*/ withWasm((wasm, pubkey: Uint8Array, input: Uint8Array, ad: Uint8Array, signature: Uint8Array): boolean => {
+  const ret = wasm.ext_bandersnatch_ietf_verify(...bridge.allocU8a(pubkey), ...bridge.allocU8a(input), ...bridge.allocU8a(ad), ...bridge.allocU8a(signature));
+
+  return ret !== 0;
+});
+
+export const bandersnatchRingNew = /*#__PURE__*/ withWasm((wasm, srs: Uint8Array, ringSize: number): number => {
+  return wasm.ext_bandersnatch_ring_new(...bridge.allocU8a(srs), ringSize);
+});
+
+export const bandersnatchRingMaxSize = /*#__PURE__*/ withWasm((wasm, ring: number): number => {
+  return wasm.ext_bandersnatch_ring_max_size(ring);
+});
+
+export const bandersnatchRingCommitment = /*#__PURE__*/ withWasm((wasm, ring: number, pubkeys: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_ring_commitment(8, ring, ...bridge.allocU8a(pubkeys));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchRingSign = /*#__PURE__*/ withWasm((wasm, ring: number, secret: Uint8Array, pubkeys: Uint8Array, index: number, input: Uint8Array, ad: Uint8Array): Uint8Array => {
+  wasm.ext_bandersnatch_ring_sign(8, ring, ...bridge.allocU8a(secret), ...bridge.allocU8a(pubkeys), index, ...bridge.allocU8a(input), ...bridge.allocU8a(ad));
+
+  return bridge.resultU8a();
+});
+
+export const bandersnatchRingVerify = /*#__PURE__*/ withWasm((wasm, ring: number, signature: Uint8Array, commitment: Uint8Array, input: Uint8Array, ad: Uint8Array): boolean => {
+  const ret = wasm.ext_bandersnatch_ring_verify(ring, ...bridge.allocU8a(signature), ...bridge.allocU8a(commitment), ...bridge.allocU8a(input), ...bridge.allocU8a(ad));
+
+  return ret !== 0;
+});
+
+export const bandersnatchRingFree = /*#__PURE__*/ withWasm((wasm, ring: number): boolean => {
+  const ret = wasm.ext_bandersnatch_ring_free(ring);
+
+  return ret !== 0;
+});
+
+export const argon2d = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, memory: number, iterations: number, parallelism: number, size: number): Uint8Array => {
+  wasm.ext_argon2d(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), memory, iterations, parallelism, size);
+
+  return bridge.resultU8a();
+});
+
+export const argon2i = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, memory: number, iterations: number, parallelism: number, size: number): Uint8Array => {
+  wasm.ext_argon2i(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), memory, iterations, parallelism, size);
+
+  return bridge.resultU8a();
+});
+
+export const argon2id = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, memory: number, iterations: number, parallelism: number, size: number): Uint8Array => {
+  wasm.ext_argon2id(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), memory, iterations, parallelism, size);
+
+  return bridge.resultU8a();
+});
+
+export const blake2b = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake2b(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), size);
+
+  return bridge.resultU8a();
+});
+
+export const blake2bParams = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, salt: Uint8Array, personal: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake2b_params(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), ...bridge.allocU8a(salt), ...bridge.allocU8a(personal), size);
+
+  return bridge.resultU8a();
+});
+
+export const blake2s = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake2s(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), size);
+
+  return bridge.resultU8a();
+});
+
+export const blake2sParams = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, salt: Uint8Array, personal: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake2s_params(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), ...bridge.allocU8a(salt), ...bridge.allocU8a(personal), size);
+
+  return bridge.resultU8a();
+});
+
+export const blake3 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, key: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake3(8, ...bridge.allocU8a(data), ...bridge.allocU8a(key), size);
+
+  return bridge.resultU8a();
+});
+
+export const blake3DeriveKey = /*#__PURE__*/ withWasm((wasm, context: string, material: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_blake3_derive_key(8, ...bridge.allocString(context), ...bridge.allocU8a(material), size);
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha256 = /*#__PURE__*/ withWasm((wasm, salt: Uint8Array, ikm: Uint8Array, info: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_hkdf_sha256(8, ...bridge.allocU8a(salt), ...bridge.allocU8a(ikm), ...bridge.allocU8a(info), size);
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha256Extract = /*#__PURE__*/ withWasm((wasm, salt: Uint8Array, ikm: Uint8Array): Uint8Array => {
+  wasm.ext_hkdf_sha256_extract(8, ...bridge.allocU8a(salt), ...bridge.allocU8a(ikm));
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha256Expand = /*#__PURE__*/ withWasm((wasm, prk: Uint8Array, info: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_hkdf_sha256_expand(8, ...bridge.allocU8a(prk), ...bridge.allocU8a(info), size);
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha512 = /*#__PURE__*/ withWasm((wasm, salt: Uint8Array, ikm: Uint8Array, info: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_hkdf_sha512(8, ...bridge.allocU8a(salt), ...bridge.allocU8a(ikm), ...bridge.allocU8a(info), size);
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha512Extract = /*#__PURE__*/ withWasm((wasm, salt: Uint8Array, ikm: Uint8Array): Uint8Array => {
+  wasm.ext_hkdf_sha512_extract(8, ...bridge.allocU8a(salt), ...bridge.allocU8a(ikm));
+
+  return bridge.resultU8a();
+});
+
+export const hkdfSha512Expand = /*#__PURE__*/ withWasm((wasm, prk: Uint8Array, info: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_hkdf_sha512_expand(8, ...bridge.allocU8a(prk), ...bridge.allocU8a(info), size);
+
+  return bridge.resultU8a();
+});
+
+export const hmacSha256 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array): Uint8Array => {
+  wasm.ext_hmac_sha256(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const hmacSha512 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array): Uint8Array => {
+  wasm.ext_hmac_sha512(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const keccak256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_keccak256(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const keccak512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_keccak512(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha3224 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha3_224(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha3256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha3_256(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha3384 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha3_384(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha3512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha3_512(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const shake128 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_shake128(8, ...bridge.allocU8a(data), size);
+
+  return bridge.resultU8a();
+});
+
+export const shake256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_shake256(8, ...bridge.allocU8a(data), size);
+
+  return bridge.resultU8a();
+});
+
+export const cshake128 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, name: Uint8Array, custom: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_cshake128(8, ...bridge.allocU8a(data), ...bridge.allocU8a(name), ...bridge.allocU8a(custom), size);
+
+  return bridge.resultU8a();
+});
+
+export const cshake256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, name: Uint8Array, custom: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_cshake256(8, ...bridge.allocU8a(data), ...bridge.allocU8a(name), ...bridge.allocU8a(custom), size);
+
+  return bridge.resultU8a();
+});
+
+export const kmac128 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array, custom: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_kmac128(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data), ...bridge.allocU8a(custom), size);
+
+  return bridge.resultU8a();
+});
+
+export const kmac256 = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, data: Uint8Array, custom: Uint8Array, size: number): Uint8Array => {
+  wasm.ext_kmac256(8, ...bridge.allocU8a(key), ...bridge.allocU8a(data), ...bridge.allocU8a(custom), size);
+
+  return bridge.resultU8a();
+});
+
+export const pbkdf2 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, salt: Uint8Array, rounds: number): Uint8Array => {
+  wasm.ext_pbkdf2(8, ...bridge.allocU8a(data), ...bridge.allocU8a(salt), rounds);
+
+  return bridge.resultU8a();
+});
+
+export const pbkdf2Sha256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, salt: Uint8Array, rounds: number, size: number): Uint8Array => {
+  wasm.ext_pbkdf2_sha256(8, ...bridge.allocU8a(data), ...bridge.allocU8a(salt), rounds, size);
+
+  return bridge.resultU8a();
+});
+
+export const pbkdf2Sha512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, salt: Uint8Array, rounds: number, size: number): Uint8Array => {
+  wasm.ext_pbkdf2_sha512(8, ...bridge.allocU8a(data), ...bridge.allocU8a(salt), rounds, size);
+
+  return bridge.resultU8a();
+});
+
+export const scrypt = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, log2n: number, r: number, p: number): Uint8Array => {
+  wasm.ext_scrypt(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), log2n, r, p);
+
+  return bridge.resultU8a();
+});
+
+export const scryptSize = /*#__PURE__*/ withWasm((wasm, password: Uint8Array, salt: Uint8Array, log2n: number, r: number, p: number, size: number): Uint8Array => {
+  wasm.ext_scrypt_size(8, ...bridge.allocU8a(password), ...bridge.allocU8a(salt), log2n, r, p, size);
+
+  return bridge.resultU8a();
+});
+
+export const sha256 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha256(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha512 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha512(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const hash160 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_hash160(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const ripemd160 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_ripemd160(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const sha256d = /*#__PURE__*/ withWasm((wasm, data: Uint8Array): Uint8Array => {
+  wasm.ext_sha256d(8, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const taggedHash = /*#__PURE__*/ withWasm((wasm, tag: string, data: Uint8Array): Uint8Array => {
+  wasm.ext_tagged_hash(8, ...bridge.allocString(tag), ...bridge.allocU8a(data));
 
   return bridge.resultU8a();
 });
@@ -268,6 +1070,160 @@ export const twox = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, rounds: numb
   return bridge.resultU8a();
 });
 
+export const xxh364 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, seed: Uint8Array): Uint8Array => {
+  wasm.ext_xxh3_64(8, ...bridge.allocU8a(data), ...bridge.allocU8a(seed));
+
+  return bridge.resultU8a();
+});
+
+export const xxh3128 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, seed: Uint8Array): Uint8Array => {
+  wasm.ext_xxh3_128(8, ...bridge.allocU8a(data), ...bridge.allocU8a(seed));
+
+  return bridge.resultU8a();
+});
+
+export const xxh64 = /*#__PURE__*/ withWasm((wasm, data: Uint8Array, seed: Uint8Array): Uint8Array => {
+  wasm.ext_xxh64(8, ...bridge.allocU8a(data), ...bridge.allocU8a(seed));
+
+  return bridge.resultU8a();
+});
+
+export const hasherUpdate = /*#__PURE__*/ withWasm((wasm, hasher: number, data: Uint8Array): void => {
+  wasm.ext_hasher_update(hasher, ...bridge.allocU8a(data));
+});
+
+export const hasherFinalize = /*#__PURE__*/ withWasm((wasm, hasher: number): Uint8Array => {
+  wasm.ext_hasher_finalize(8, hasher);
+
+  return bridge.resultU8a();
+});
+
+export const hasherFree = /*#__PURE__*/ withWasm((wasm, hasher: number): boolean => {
+  const ret = wasm.ext_hasher_free(hasher);
+
+  return ret !== 0;
+});
+
+export const blake2bHasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, size: number): number => {
+  return wasm.ext_blake2b_hasher_new(...bridge.allocU8a(key), size);
+});
+
+export const blake2bParamsHasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, salt: Uint8Array, personal: Uint8Array, size: number): number => {
+  return wasm.ext_blake2b_params_hasher_new(...bridge.allocU8a(key), ...bridge.allocU8a(salt), ...bridge.allocU8a(personal), size);
+});
+
+export const blake2sHasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, size: number): number => {
+  return wasm.ext_blake2s_hasher_new(...bridge.allocU8a(key), size);
+});
+
+export const blake2sParamsHasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, salt: Uint8Array, personal: Uint8Array, size: number): number => {
+  return wasm.ext_blake2s_params_hasher_new(...bridge.allocU8a(key), ...bridge.allocU8a(salt), ...bridge.allocU8a(personal), size);
+});
+
+export const blake3HasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, size: number): number => {
+  return wasm.ext_blake3_hasher_new(...bridge.allocU8a(key), size);
+});
+
+export const blake3DeriveKeyHasherNew = /*#__PURE__*/ withWasm((wasm, context: string, size: number): number => {
+  return wasm.ext_blake3_derive_key_hasher_new(...bridge.allocString(context), size);
+});
+
+export const keccak256HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_keccak256_hasher_new();
+});
+
+export const keccak512HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_keccak512_hasher_new();
+});
+
+export const sha3224HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha3_224_hasher_new();
+});
+
+export const sha3256HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha3_256_hasher_new();
+});
+
+export const sha3384HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha3_384_hasher_new();
+});
+
+export const sha3512HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha3_512_hasher_new();
+});
+
+export const shake128HasherNew = /*#__PURE__*/ withWasm((wasm, size: number): number => {
+  return wasm.ext_shake128_hasher_new(size);
+});
+
+export const shake256HasherNew = /*#__PURE__*/ withWasm((wasm, size: number): number => {
+  return wasm.ext_shake256_hasher_new(size);
+});
+
+export const cshake128HasherNew = /*#__PURE__*/ withWasm((wasm, name: Uint8Array, custom: Uint8Array, size: number): number => {
+  return wasm.ext_cshake128_hasher_new(...bridge.allocU8a(name), ...bridge.allocU8a(custom), size);
+});
+
+export const cshake256HasherNew = /*#__PURE__*/ withWasm((wasm, name: Uint8Array, custom: Uint8Array, size: number): number => {
+  return wasm.ext_cshake256_hasher_new(...bridge.allocU8a(name), ...bridge.allocU8a(custom), size);
+});
+
+export const kmac128HasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, custom: Uint8Array, size: number): number => {
+  return wasm.ext_kmac128_hasher_new(...bridge.allocU8a(key), ...bridge.allocU8a(custom), size);
+});
+
+export const kmac256HasherNew = /*#__PURE__*/ withWasm((wasm, key: Uint8Array, custom: Uint8Array, size: number): number => {
+  return wasm.ext_kmac256_hasher_new(...bridge.allocU8a(key), ...bridge.allocU8a(custom), size);
+});
+
+export const sha256HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha256_hasher_new();
+});
+
+export const sha512HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha512_hasher_new();
+});
+
+export const hash160HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_hash160_hasher_new();
+});
+
+export const ripemd160HasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_ripemd160_hasher_new();
+});
+
+export const sha256dHasherNew = /*#__PURE__*/ withWasm((wasm): number => {
+  return wasm.ext_sha256d_hasher_new();
+});
+
+export const taggedHasherNew = /*#__PURE__*/ withWasm((wasm, tag: string): number => {
+  return wasm.ext_tagged_hasher_new(...bridge.allocString(tag));
+});
+
+export const twoxHasherNew = /*#__PURE__*/ withWasm((wasm, rounds: number): number => {
+  return wasm.ext_twox_hasher_new(rounds);
+});
+
+export const xxh3HasherNew = /*#__PURE__*/ withWasm((wasm, seed: Uint8Array, size: number): number => {
+  return wasm.ext_xxh3_hasher_new(...bridge.allocU8a(seed), size);
+});
+
+export const xxh64HasherNew = /*#__PURE__*/ withWasm((wasm, seed: Uint8Array): number => {
+  return wasm.ext_xxh64_hasher_new(...bridge.allocU8a(seed));
+});
+
+export const storageHash = /*#__PURE__*/ withWasm((wasm, hasher: number, data: Uint8Array): Uint8Array => {
+  wasm.ext_storage_hash(8, hasher, ...bridge.allocU8a(data));
+
+  return bridge.resultU8a();
+});
+
+export const storageKey = /*#__PURE__*/ withWasm((wasm, pallet: string, item: string, hashers: Uint32Array, keys: Uint8Array, lengths: Uint32Array): Uint8Array => {
+  wasm.ext_storage_key(8, ...bridge.allocString(pallet), ...bridge.allocString(item), ...bridge.allocU32a(hashers), ...bridge.allocU8a(keys), ...bridge.allocU32a(lengths));
+
+  return bridge.resultU8a();
+});
+
 export function isReady (): boolean {
   return !!bridge.wasm;
 }
@@ -5,21 +5,42 @@
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+#[path = "rs/babe.rs"]
+pub mod babe;
+
+#[path = "rs/bandersnatch.rs"]
+pub mod bandersnatch;
+
 #[path = "rs/bip39.rs"]
 pub mod bip39;
 
 #[path = "rs/ed25519.rs"]
 pub mod ed25519;
 
+#[path = "rs/ethereum.rs"]
+pub mod ethereum;
+
+#[path = "rs/frost.rs"]
+pub mod frost;
+
+#[path = "rs/handles.rs"]
+pub mod handles;
+
 #[path = "rs/hashing.rs"]
 pub mod hashing;
 
+#[path = "rs/musig2.rs"]
+pub mod musig2;
+
 #[path = "rs/secp256k1.rs"]
 pub mod secp256k1;
 
 #[path = "rs/sr25519.rs"]
 pub mod sr25519;
 
+#[path = "rs/storage.rs"]
+pub mod storage;
+
 #[path = "rs/vrf.rs"]
 pub mod vrf;
 
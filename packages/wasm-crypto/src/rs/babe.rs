@@ -0,0 +1,339 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use merlin::Transcript;
+use wasm_bindgen::prelude::*;
+
+use crate::hashing::ext_blake2b;
+use crate::sr25519::u64_from_le;
+use crate::vrf::{check_threshold, vrf_inout, vrf_sign, vrf_verify, RESULT_SIZE, THRESHOLD_SIZE};
+
+/// The BABE engine id, used as transcript label
+const BABE_ENGINE_ID: &[u8] = b"BABE";
+
+/// The make_bytes context for the primary slot authoring score
+const BABE_VRF_PREFIX: &[u8] = b"substrate-babe-vrf";
+
+/// Size of the epoch randomness, bytes
+pub const RANDOMNESS_SIZE: usize = 32;
+
+/// Slot claim result of ext_babe_claim_slot, the slot is not claimable
+pub const CLAIM_NONE: u32 = 0;
+
+/// Slot claim result of ext_babe_claim_slot, the slot is a primary claim
+pub const CLAIM_PRIMARY: u32 = 1;
+
+/// Slot claim result of ext_babe_claim_slot, the slot is a secondary claim
+pub const CLAIM_SECONDARY: u32 = 2;
+
+/// Create the BABE VRF transcript, as per sp_consensus_babe::make_vrf_transcript
+fn make_transcript(randomness: &[u8], slot: &[u8], epoch: &[u8]) -> Transcript {
+	if randomness.len() != RANDOMNESS_SIZE {
+		panic!("Invalid randomness provided.");
+	}
+
+	let (slot, epoch) = match (u64_from_le(slot), u64_from_le(epoch)) {
+		(Some(s), Some(e)) => (s, e),
+		_ => panic!("Invalid slot or epoch provided.")
+	};
+	let mut transcript = Transcript::new(BABE_ENGINE_ID);
+
+	transcript.append_u64(b"slot number", slot);
+	transcript.append_u64(b"current epoch", epoch);
+	transcript.append_message(b"chain randomness", randomness);
+
+	transcript
+}
+
+/// Exact floor(p * 2^128), matching the BigRational::from_float calculation in Substrate
+fn scale_probability(p: f64) -> u128 {
+	if p.is_nan() || p >= 1.0 {
+		panic!("Invalid threshold parameters.");
+	} else if p <= 0.0 {
+		return 0;
+	}
+
+	let bits = p.to_bits();
+	let exponent = ((bits >> 52) & 0x7ff) as i32;
+	let (mantissa, exponent) = match exponent {
+		0 => ((bits & 0xf_ffff_ffff_ffff) as u128, -1074),
+		e => (((bits & 0xf_ffff_ffff_ffff) | (1 << 52)) as u128, e - 1075),
+	};
+
+	// since p < 1, mantissa * 2^(exponent + 128) is always below 2^128
+	match exponent + 128 {
+		s if s >= 0 => mantissa << s,
+		s if s > -128 => mantissa >> -s,
+		_ => 0
+	}
+}
+
+/// Calculate the BABE primary slot threshold for an authority
+///
+/// * c_numer: UIntArray with 8 element, the numerator of the BABE c configuration value as little-endian u64
+/// * c_denom: UIntArray with 8 element, the denominator of the BABE c configuration value as little-endian u64
+/// * weight: UIntArray with 8 element, the weight of the authority as little-endian u64
+/// * total_weight: UIntArray with 8 element, the sum of the weights of all authorities in the epoch as little-endian u64
+///
+/// returned vector is the u128 threshold as 16 little-endian bytes
+#[wasm_bindgen]
+pub fn ext_babe_primary_threshold(c_numer: &[u8], c_denom: &[u8], weight: &[u8], total_weight: &[u8]) -> Vec<u8> {
+	let (c_numer, c_denom, weight, total_weight) = match (u64_from_le(c_numer), u64_from_le(c_denom), u64_from_le(weight), u64_from_le(total_weight)) {
+		(Some(n), Some(d), Some(w), Some(t)) => (n, d, w, t),
+		_ => panic!("Invalid threshold parameters.")
+	};
+
+	if c_denom == 0 || c_numer > c_denom || weight == 0 || weight > total_weight {
+		panic!("Invalid threshold parameters.");
+	}
+
+	let c = c_numer as f64 / c_denom as f64;
+	let theta = weight as f64 / total_weight as f64;
+
+	scale_probability(1f64 - (1f64 - c).powf(theta))
+		.to_le_bytes()
+		.to_vec()
+}
+
+/// Calculate the expected secondary slot author
+///
+/// * randomness: UIntArray with 32 element, the epoch randomness
+/// * slot: UIntArray with 8 element, the slot number as little-endian u64
+/// * authorities: the number of authorities in the epoch
+///
+/// returned value is the index of the authority
+#[wasm_bindgen]
+pub fn ext_babe_secondary_author(randomness: &[u8], slot: &[u8], authorities: u32) -> u32 {
+	if randomness.len() != RANDOMNESS_SIZE {
+		panic!("Invalid randomness provided.");
+	} else if u64_from_le(slot).is_none() {
+		panic!("Invalid slot or epoch provided.");
+	} else if authorities == 0 {
+		panic!("Invalid authorities provided.");
+	}
+
+	// blake2(randomness ++ slot) as big-endian integer, modulo the number of authorities
+	ext_blake2b(&[randomness, slot].concat(), &[], 32)
+		.iter()
+		.fold(0u64, |r, b| ((r << 8) | *b as u64) % authorities as u64) as u32
+}
+
+/// Create the VRF output and proof for a BABE slot
+///
+/// * secret: UIntArray with 64 element
+/// * randomness: UIntArray with 32 element, the epoch randomness
+/// * slot: UIntArray with 8 element, the slot number as little-endian u64
+/// * epoch: UIntArray with 8 element, the epoch index as little-endian u64
+///
+/// * returned vector is the 32-byte output (pre-output) and 64-byte proof.
+#[wasm_bindgen]
+pub fn ext_babe_vrf_sign(secret: &[u8], randomness: &[u8], slot: &[u8], epoch: &[u8]) -> Vec<u8> {
+	vrf_sign(secret, make_transcript(randomness, slot, epoch), &[])
+}
+
+/// Verify the VRF output and proof for a BABE slot
+///
+/// * pubkey: UIntArray with 32 element, the authority key
+/// * randomness: UIntArray with 32 element, the epoch randomness
+/// * slot: UIntArray with 8 element, the slot number as little-endian u64
+/// * epoch: UIntArray with 8 element, the epoch index as little-endian u64
+/// * out: 96-byte output & proof array from the pre-digest (or ext_babe_vrf_sign)
+#[wasm_bindgen]
+pub fn ext_babe_vrf_verify(pubkey: &[u8], randomness: &[u8], slot: &[u8], epoch: &[u8], out: &[u8]) -> bool {
+	out.len() == RESULT_SIZE && vrf_verify(pubkey, make_transcript(randomness, slot, epoch), &[], out)
+}
+
+/// Determine the claim an authority has on a BABE slot
+///
+/// * pubkey: UIntArray with 32 element, the authority key
+/// * authority_index: the index of the authority in the epoch
+/// * authorities: the number of authorities in the epoch
+/// * randomness: UIntArray with 32 element, the epoch randomness
+/// * slot: UIntArray with 8 element, the slot number as little-endian u64
+/// * epoch: UIntArray with 8 element, the epoch index as little-endian u64
+/// * out: 96-byte output & proof array (or empty for plain secondary claims)
+/// * threshold: UIntArray with 16 element, from ext_babe_primary_threshold
+///
+/// returned value is CLAIM_PRIMARY (1) when the VRF output is below the threshold,
+/// CLAIM_SECONDARY (2) when the authority is the expected secondary author and
+/// CLAIM_NONE (0) otherwise, including for invalid VRF proofs
+#[wasm_bindgen]
+pub fn ext_babe_claim_slot(pubkey: &[u8], authority_index: u32, authorities: u32, randomness: &[u8], slot: &[u8], epoch: &[u8], out: &[u8], threshold: &[u8]) -> u32 {
+	if threshold.len() != THRESHOLD_SIZE {
+		panic!("Invalid threshold provided.");
+	}
+
+	if !out.is_empty() {
+		if !ext_babe_vrf_verify(pubkey, randomness, slot, epoch, out) {
+			return CLAIM_NONE;
+		}
+
+		let is_primary = match vrf_inout(pubkey, make_transcript(randomness, slot, epoch), out) {
+			Some(inout) => check_threshold(&inout, BABE_VRF_PREFIX, threshold),
+			_ => false
+		};
+
+		if is_primary {
+			return CLAIM_PRIMARY;
+		}
+	}
+
+	match authority_index == ext_babe_secondary_author(randomness, slot, authorities) {
+		true => CLAIM_SECONDARY,
+		false => CLAIM_NONE
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	extern crate rand;
+
+	use hex_literal::hex;
+	use schnorrkel::{KEYPAIR_LENGTH, SECRET_KEY_LENGTH};
+	use super::*;
+	use crate::sr25519::{ext_sr_from_seed, ext_sr_transcript_append_message, ext_sr_transcript_append_u64, ext_sr_transcript_new};
+	use crate::vrf::{ext_vrf_make_bytes_transcript, ext_vrf_verify_transcript, RAW_OUTPUT_SIZE};
+
+	const RANDOMNESS: [u8; 32] = hex!("b60e504d2e27d13c510e8dcea0326ae9237b04b942b6c19c132d899b7bba0b78");
+
+	fn generate_random_seed() -> Vec<u8> {
+		(0..32).map(|_| rand::random::<u8>()).collect()
+	}
+
+	fn le(value: u64) -> [u8; 8] {
+		value.to_le_bytes()
+	}
+
+	fn threshold(c: (u64, u64), weights: &[u64], weight: u64) -> u128 {
+		let mut res = [0u8; 16];
+
+		res.copy_from_slice(&ext_babe_primary_threshold(&le(c.0), &le(c.1), &le(weight), &le(weights.iter().sum())));
+
+		u128::from_le_bytes(res)
+	}
+
+	#[test]
+	fn can_primary_threshold() {
+		// smoldot calculate_primary_threshold_tests, generated with the Substrate implementation
+		assert_eq!(threshold((2, 9), &[1; 8], 1), 10523572781773471998586657386560225280u128);
+		assert_eq!(threshold((103, 971), &(2..902).collect::<Vec<u64>>(), 11), 1032931305829506557190946382938112u128);
+		assert_eq!(threshold((89, 91), &std::iter::successors(Some(2u64), |n| Some(n * 2 - 1)).take(50).collect::<Vec<u64>>(), 63), 72686664904329579129208832u128);
+		assert_eq!(threshold((0, 4), &[1; 8], 1), 0);
+	}
+
+	#[test]
+	fn can_secondary_author() {
+		let authorities = 297;
+		let hash = ext_blake2b(&[&RANDOMNESS[..], &le(1234567)[..]].concat(), &[], 32);
+		let expected = hash
+			.iter()
+			.fold(0u128, |r, b| ((r << 8) | *b as u128) % authorities as u128);
+
+		assert_eq!(ext_babe_secondary_author(&RANDOMNESS, &le(1234567), authorities) as u128, expected);
+		assert_eq!(ext_babe_secondary_author(&RANDOMNESS, &le(1234567), 1), 0);
+	}
+
+	#[test]
+	fn can_verify_polkadot_headers() {
+		// Polkadot blocks #1 & #2 (primary claims in the genesis epoch), as in the smoldot
+		// polkadot_blocks_0_to_2 test, with the genesis BABE authorities and c = (1, 4)
+		let authorities = [
+			hex!("fa3437b10f6e7af8f31362df3a179b991a8c56313d1bcd6307a4d0c734c1ae31"),
+			hex!("d2419bc8835493ac89eb09d5985281f5dff4bc6c7a7ea988fd23af05f301580a"),
+			hex!("ccb6bef60defc30724545d57440394ed1c71ea7ee6d880ed0e79871a05b5e406"),
+			hex!("5e67b64cf07d4d258a47df63835121423551712844f5b67de68e36bb9a21e127"),
+			hex!("6236877b05370265640c133fec07e64d7ca823db1dc56f2d3584b3d7c0f16158"),
+			hex!("6c52d02d95c30aa567fda284acf25025ca7470f0b0c516ddf94475a1807c4d25")
+		];
+		let headers = [
+			(0, 265084563, hex!("362ed8d6055645487fe42e9c8640be651f70a3a2a03658046b2b43f021665704501af9b1ca6e974c257e3d26609b5f68b5b0a1da53f7f252bbe5d94948c39705c98ffa4b869dd44ac29528e3723d619cc7edf1d3f7b7a57a957f6a7e9bdb270a")),
+			(1, 265084568, hex!("443f77b9ee55169823c50bdacbea18a74fa0673aaf97e01e7ad9675efcab3676507cd217827dc6d8421af5012befbad98e267785d706827387306a23a487d50135817053540f43a1b0f8ef9173502fd764f3116ab0855bb75e9e1f2a8880620c"))
+		];
+		let randomness = [0u8; RANDOMNESS_SIZE];
+		let threshold = ext_babe_primary_threshold(&le(1), &le(4), &le(1), &le(authorities.len() as u64));
+
+		for (index, slot, out) in headers.iter() {
+			let pubkey = &authorities[*index as usize];
+
+			assert!(ext_babe_vrf_verify(pubkey, &randomness, &le(*slot), &le(0), out));
+			assert!(!ext_babe_vrf_verify(pubkey, &randomness, &le(*slot), &le(1), out));
+			assert!(!ext_babe_vrf_verify(&authorities[*index as usize + 1], &randomness, &le(*slot), &le(0), out));
+			assert_eq!(ext_babe_claim_slot(pubkey, *index, authorities.len() as u32, &randomness, &le(*slot), &le(0), out, &threshold), CLAIM_PRIMARY);
+		}
+	}
+
+	#[test]
+	fn can_claim_kusama_secondary() {
+		// Kusama blocks #1 & #2 (secondary plain claims in the genesis epoch, with 6
+		// authorities), as in the smoldot kusama_blocks_0_to_2 test
+		let pubkey = hex!("b46f01874ce7abbb5220e8fd89bede0adad14c73039d91e28e881823433e723f");
+		let randomness = [0u8; RANDOMNESS_SIZE];
+		let threshold = ext_babe_primary_threshold(&le(1), &le(4), &le(1), &le(6));
+
+		assert_eq!(ext_babe_secondary_author(&randomness, &le(262493679), 6), 1);
+		assert_eq!(ext_babe_secondary_author(&randomness, &le(262493682), 6), 3);
+		assert_eq!(ext_babe_claim_slot(&pubkey, 1, 6, &randomness, &le(262493679), &le(0), &[], &threshold), CLAIM_SECONDARY);
+		assert_eq!(ext_babe_claim_slot(&pubkey, 1, 6, &randomness, &le(262493682), &le(0), &[], &threshold), CLAIM_NONE);
+	}
+
+	#[test]
+	fn can_vrf_sign_and_verify() {
+		let keypair = ext_sr_from_seed(&generate_random_seed());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let out = ext_babe_vrf_sign(private, &RANDOMNESS, &le(270000000), &le(4500));
+		let transcript = ext_sr_transcript_new(b"BABE");
+
+		ext_sr_transcript_append_u64(transcript, b"slot number", &le(270000000));
+		ext_sr_transcript_append_u64(transcript, b"current epoch", &le(4500));
+		ext_sr_transcript_append_message(transcript, b"chain randomness", &RANDOMNESS);
+
+		assert!(ext_babe_vrf_verify(public, &RANDOMNESS, &le(270000000), &le(4500), &out));
+		assert!(ext_vrf_verify_transcript(public, transcript, &[], &out));
+		assert!(!ext_babe_vrf_verify(public, &RANDOMNESS, &le(270000001), &le(4500), &out));
+		assert!(!ext_babe_vrf_verify(public, &RANDOMNESS, &le(270000000), &le(4501), &out));
+		assert!(!ext_babe_vrf_verify(public, &[0u8; 32], &le(270000000), &le(4500), &out));
+	}
+
+	#[test]
+	fn can_claim_slot() {
+		let keypair = ext_sr_from_seed(&generate_random_seed());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let slot = 270000000;
+		let out = ext_babe_vrf_sign(private, &RANDOMNESS, &le(slot), &le(4500));
+		let transcript = ext_sr_transcript_new(b"BABE");
+
+		ext_sr_transcript_append_u64(transcript, b"slot number", &le(slot));
+		ext_sr_transcript_append_u64(transcript, b"current epoch", &le(4500));
+		ext_sr_transcript_append_message(transcript, b"chain randomness", &RANDOMNESS);
+
+		let mut value = [0u8; RAW_OUTPUT_SIZE];
+
+		value.copy_from_slice(&ext_vrf_make_bytes_transcript(public, transcript, &out, b"substrate-babe-vrf", RAW_OUTPUT_SIZE as u32));
+
+		let value = u128::from_le_bytes(value);
+		let secondary = ext_babe_secondary_author(&RANDOMNESS, &le(slot), 10);
+		let other = (secondary + 1) % 10;
+
+		assert_eq!(ext_babe_claim_slot(public, other, 10, &RANDOMNESS, &le(slot), &le(4500), &out, &(value + 1).to_le_bytes()), CLAIM_PRIMARY);
+		assert_eq!(ext_babe_claim_slot(public, other, 10, &RANDOMNESS, &le(slot), &le(4500), &out, &value.to_le_bytes()), CLAIM_NONE);
+		assert_eq!(ext_babe_claim_slot(public, secondary, 10, &RANDOMNESS, &le(slot), &le(4500), &out, &value.to_le_bytes()), CLAIM_SECONDARY);
+		assert_eq!(ext_babe_claim_slot(public, secondary, 10, &RANDOMNESS, &le(slot), &le(4500), &[], &u128::MAX.to_le_bytes()), CLAIM_SECONDARY);
+		assert_eq!(ext_babe_claim_slot(public, secondary, 10, &RANDOMNESS, &le(slot + 1), &le(4500), &out, &u128::MAX.to_le_bytes()), CLAIM_NONE);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid slot or epoch provided.")]
+	fn vrf_sign_fails_on_invalid_slot() {
+		let keypair = ext_sr_from_seed(&generate_random_seed());
+
+		ext_babe_vrf_sign(&keypair[0..SECRET_KEY_LENGTH], &RANDOMNESS, &270000000u32.to_le_bytes(), &le(4500));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid threshold parameters.")]
+	fn primary_threshold_fails_on_invalid_weight() {
+		ext_babe_primary_threshold(&le(1), &le(4), &[1], &le(6));
+	}
+}
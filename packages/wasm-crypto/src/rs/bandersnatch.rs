@@ -0,0 +1,433 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use ark_vrf::ietf::{Prover as IetfProver, Verifier as IetfVerifier};
+use ark_vrf::reexports::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_vrf::ring::{Prover as RingProver, Verifier as RingVerifier};
+use ark_vrf::suites::bandersnatch::{AffinePoint, IetfProof, Input, Output, PcsParams, Public, RingCommitment, RingProof, RingProofParams, Secret};
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use crate::handles::{self, Handles};
+
+/// Size of secret scalars, public keys and VRF output points, bytes
+pub const KEY_SIZE: usize = 32;
+
+/// Size of the IETF VRF proof (c || s), bytes
+pub const IETF_PROOF_SIZE: usize = 64;
+
+/// Compressed serialization helper, returning the encoded bytes
+fn serialize<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+	let mut res = Vec::with_capacity(value.compressed_size());
+
+	match value.serialize_compressed(&mut res) {
+		Ok(_) => res,
+		_ => panic!("Invalid value provided.")
+	}
+}
+
+/// Secret helper function, panicking on an invalid scalar
+fn secret_from_bytes(secret: &[u8]) -> Secret {
+	match secret.len() {
+		KEY_SIZE => match Secret::deserialize_compressed(secret) {
+			Ok(s) => s,
+			_ => panic!("Invalid secret provided.")
+		},
+		_ => panic!("Invalid secret provided.")
+	}
+}
+
+/// Decode a compressed curve point, i.e. a public key or VRF output
+fn point_from_bytes(point: &[u8]) -> Option<AffinePoint> {
+	match point.len() {
+		KEY_SIZE => AffinePoint::deserialize_compressed(point).ok(),
+		_ => None
+	}
+}
+
+/// Split concatenated public keys into ring members, panicking on any invalid key
+fn split_pubkeys(pubkeys: &[u8]) -> Vec<AffinePoint> {
+	if pubkeys.is_empty() || pubkeys.len() % KEY_SIZE != 0 {
+		panic!("Invalid pubkeys provided.");
+	}
+
+	pubkeys
+		.chunks(KEY_SIZE)
+		.map(|p| match point_from_bytes(p) {
+			Some(p) => p,
+			_ => panic!("Invalid pubkeys provided.")
+		})
+		.collect()
+}
+
+/// Map the input bytes to the curve (Elligator 2), panicking when no point is found
+fn vrf_input(input: &[u8]) -> Input {
+	match Input::new(input) {
+		Some(i) => i,
+		_ => panic!("Invalid input provided.")
+	}
+}
+
+/// Generate a key pair from a seed
+///
+/// * seed: UIntArray with 32 element
+///
+/// returned vector is the concatenation of the secret scalar (32 bytes)
+/// followed by the public key (32 bytes)
+#[wasm_bindgen]
+pub fn ext_bandersnatch_from_seed(seed: &[u8]) -> Vec<u8> {
+	if seed.len() != 32 {
+		panic!("Invalid seed provided.");
+	}
+
+	let secret = Secret::from_seed(seed);
+	let mut res = serialize(&secret);
+
+	res.extend_from_slice(&serialize(&secret.public()));
+
+	res
+}
+
+/// Returns the public key for a secret
+///
+/// * secret: UIntArray with 32 element
+///
+/// returned vector is the compressed public key
+#[wasm_bindgen]
+pub fn ext_bandersnatch_public(secret: &[u8]) -> Vec<u8> {
+	serialize(&secret_from_bytes(secret).public())
+}
+
+/// Returns the VRF output point for an input, without a proof
+///
+/// * secret: UIntArray with 32 element
+/// * input: Arbitrary length UIntArray, mapped to the curve
+///
+/// returned vector is the compressed output point (32 bytes)
+#[wasm_bindgen]
+pub fn ext_bandersnatch_vrf_output(secret: &[u8], input: &[u8]) -> Vec<u8> {
+	serialize(&secret_from_bytes(secret).output(vrf_input(input)))
+}
+
+/// Hash a VRF output point into the VRF output bytes
+///
+/// * output: UIntArray with 32 element, the output point
+///
+/// returned vector is the 64-byte output hash
+#[wasm_bindgen]
+pub fn ext_bandersnatch_output_hash(output: &[u8]) -> Vec<u8> {
+	match point_from_bytes(output) {
+		Some(p) => Output::from(p).hash().to_vec(),
+		_ => panic!("Invalid output provided.")
+	}
+}
+
+/// Sign an input with the IETF VRF
+///
+/// * secret: UIntArray with 32 element
+/// * input: Arbitrary length UIntArray, mapped to the curve
+/// * ad: Arbitrary length UIntArray, additional data signed alongside
+///
+/// returned vector is the output point (32 bytes) followed by the proof (64 bytes)
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ietf_sign(secret: &[u8], input: &[u8], ad: &[u8]) -> Vec<u8> {
+	let secret = secret_from_bytes(secret);
+	let input = vrf_input(input);
+	let output = secret.output(input);
+	let proof = IetfProver::prove(&secret, input, output, ad);
+	let mut res = serialize(&output);
+
+	res.extend_from_slice(&serialize(&proof));
+
+	res
+}
+
+/// Verify an IETF VRF signature
+///
+/// * pubkey: UIntArray with 32 element
+/// * input: Arbitrary length UIntArray, mapped to the curve
+/// * ad: Arbitrary length UIntArray, additional data signed alongside
+/// * signature: UIntArray with 96 element, output followed by proof
+///
+/// returned true if the proof is valid for the output
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ietf_verify(pubkey: &[u8], input: &[u8], ad: &[u8], signature: &[u8]) -> bool {
+	if signature.len() != KEY_SIZE + IETF_PROOF_SIZE {
+		return false;
+	}
+
+	let (output, proof) = signature.split_at(KEY_SIZE);
+
+	match (point_from_bytes(pubkey), point_from_bytes(output), IetfProof::deserialize_compressed(proof), Input::new(input)) {
+		(Some(p), Some(o), Ok(proof), Some(i)) => IetfVerifier::verify(&Public::from(p), i, Output::from(o), ad, &proof).is_ok(),
+		_ => false
+	}
+}
+
+/// Ring VRF context, wrapping the ring proof parameters for a maximum ring size
+///
+/// The parameters are derived from a KZG structured reference string, i.e. the
+/// uncompressed zcash BLS12-381 powers of tau as used by Sassafras. Deserializing
+/// and preparing these is expensive, so a context is kept WASM-side and re-used.
+struct BandersnatchRing {
+	params: RingProofParams
+}
+
+impl BandersnatchRing {
+	fn new(srs: &[u8], ring_size: u32) -> BandersnatchRing {
+		let pcs = match PcsParams::deserialize_uncompressed_unchecked(srs) {
+			Ok(p) => p,
+			_ => panic!("Invalid srs provided.")
+		};
+
+		match RingProofParams::from_pcs_params(ring_size as usize, pcs) {
+			Ok(params) => BandersnatchRing { params },
+			_ => panic!("Invalid ring size provided.")
+		}
+	}
+
+	fn commitment(&self, pubkeys: &[u8]) -> Vec<u8> {
+		let pubkeys = self.ring(pubkeys);
+
+		serialize(&self.params.verifier_key(&pubkeys).commitment())
+	}
+
+	fn sign(&self, secret: &[u8], pubkeys: &[u8], index: u32, input: &[u8], ad: &[u8]) -> Vec<u8> {
+		let secret = secret_from_bytes(secret);
+		let pubkeys = self.ring(pubkeys);
+
+		if pubkeys.get(index as usize) != Some(&secret.public().0) {
+			panic!("Invalid index provided.");
+		}
+
+		let input = vrf_input(input);
+		let output = secret.output(input);
+		let prover = self.params.prover(self.params.prover_key(&pubkeys), index as usize);
+		let proof = RingProver::prove(&secret, input, output, ad, &prover);
+		let mut res = serialize(&output);
+
+		res.extend_from_slice(&serialize(&proof));
+
+		res
+	}
+
+	fn verify(&self, signature: &[u8], commitment: &[u8], input: &[u8], ad: &[u8]) -> bool {
+		if signature.len() <= KEY_SIZE {
+			return false;
+		}
+
+		let (output, proof) = signature.split_at(KEY_SIZE);
+
+		match (point_from_bytes(output), RingProof::deserialize_compressed(proof), RingCommitment::deserialize_compressed(commitment), Input::new(input)) {
+			(Some(o), Ok(proof), Ok(c), Some(i)) => {
+				let verifier = self.params.verifier(self.params.verifier_key_from_commitment(c));
+
+				<Public as RingVerifier<_>>::verify(i, Output::from(o), ad, &proof, &verifier).is_ok()
+			},
+			_ => false
+		}
+	}
+
+	fn ring(&self, pubkeys: &[u8]) -> Vec<AffinePoint> {
+		let pubkeys = split_pubkeys(pubkeys);
+
+		if pubkeys.len() > self.params.max_ring_size() {
+			panic!("Invalid pubkeys provided.");
+		}
+
+		pubkeys
+	}
+}
+
+thread_local! {
+	static RINGS: RefCell<Handles<BandersnatchRing>> = RefCell::new(Handles::new());
+}
+
+/// Create a ring context
+///
+/// * srs: UIntArray, the uncompressed KZG parameters
+/// * ring_size: the number of ring members the context should support
+///
+/// returned value is the handle of the ring context
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_new(srs: &[u8], ring_size: u32) -> u32 {
+	handles::insert(&RINGS, BandersnatchRing::new(srs, ring_size))
+}
+
+/// Returns the maximum number of ring members supported by the context
+///
+/// * ring: the ring context handle
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_max_size(ring: u32) -> u32 {
+	handles::with(&RINGS, ring, |r| r.params.max_ring_size() as u32)
+}
+
+/// Construct the commitment for a ring of public keys
+///
+/// * ring: the ring context handle
+/// * pubkeys: UIntArray, concatenated 32-byte public keys
+///
+/// returned vector is the serialized ring commitment
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_commitment(ring: u32, pubkeys: &[u8]) -> Vec<u8> {
+	handles::with(&RINGS, ring, |r| r.commitment(pubkeys))
+}
+
+/// Sign an input with the ring VRF, proving membership without revealing the signer
+///
+/// * ring: the ring context handle
+/// * secret: UIntArray with 32 element
+/// * pubkeys: UIntArray, concatenated 32-byte public keys of the ring
+/// * index: the position of the signer's public key in the ring
+/// * input: Arbitrary length UIntArray, mapped to the curve
+/// * ad: Arbitrary length UIntArray, additional data signed alongside
+///
+/// returned vector is the output point (32 bytes) followed by the ring proof
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_sign(ring: u32, secret: &[u8], pubkeys: &[u8], index: u32, input: &[u8], ad: &[u8]) -> Vec<u8> {
+	handles::with(&RINGS, ring, |r| r.sign(secret, pubkeys, index, input, ad))
+}
+
+/// Verify a ring VRF signature against a ring commitment
+///
+/// * ring: the ring context handle
+/// * signature: UIntArray, output followed by the ring proof
+/// * commitment: UIntArray, as returned from ext_bandersnatch_ring_commitment
+/// * input: Arbitrary length UIntArray, mapped to the curve
+/// * ad: Arbitrary length UIntArray, additional data signed alongside
+///
+/// returned true if the signer is a member of the committed ring
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_verify(ring: u32, signature: &[u8], commitment: &[u8], input: &[u8], ad: &[u8]) -> bool {
+	handles::with(&RINGS, ring, |r| r.verify(signature, commitment, input, ad))
+}
+
+/// Release the ring context
+///
+/// * ring: the ring context handle
+///
+/// returned true if the handle referred to a live context
+#[wasm_bindgen]
+pub fn ext_bandersnatch_ring_free(ring: u32) -> bool {
+	handles::remove(&RINGS, ring).is_some()
+}
+
+#[cfg(test)]
+pub mod tests {
+	extern crate rand;
+
+	use hex_literal::hex;
+	use super::*;
+
+	const RING_SIZE: usize = 8;
+
+	fn generate_random_seed() -> Vec<u8> {
+		(0..32).map(|_| rand::random::<u8>()).collect()
+	}
+
+	fn test_srs() -> Vec<u8> {
+		// insecure, the toxic waste is derivable from the seed, only for testing
+		let params = RingProofParams::from_seed(RING_SIZE, [0u8; 32]);
+		let mut srs = Vec::new();
+
+		match params.pcs.serialize_uncompressed(&mut srs) {
+			Ok(_) => srs,
+			_ => panic!("Invalid srs provided.")
+		}
+	}
+
+	fn ring_keys() -> (Vec<Vec<u8>>, Vec<u8>) {
+		let pairs: Vec<Vec<u8>> = (0..RING_SIZE).map(|_| ext_bandersnatch_from_seed(&generate_random_seed())).collect();
+		let pubkeys = pairs.iter().flat_map(|p| p[32..].to_vec()).collect();
+
+		(pairs, pubkeys)
+	}
+
+	#[test]
+	fn can_create_keypair() {
+		let pair = ext_bandersnatch_from_seed(&generate_random_seed());
+
+		assert_eq!(pair.len(), 64);
+		assert_eq!(ext_bandersnatch_public(&pair[..32]), pair[32..].to_vec());
+	}
+
+	#[test]
+	fn can_verify_ietf_vectors() {
+		// bandersnatch_sha-512_ell2_ietf vector-1 & vector-2 from ark-vrf
+		let vectors = [
+			(
+				hex!("3d6406500d4009fdf2604546093665911e753f2213570a29521fd88bc30ede18"),
+				hex!("a1b1da71cc4682e159b7da23050d8b6261eb11a3247c89b07ef56ccd002fd38b"),
+				vec![],
+				hex!("e7aa5154103450f0a0525a36a441f827296ee489ef30ed8787cff8df1bef223f"),
+				hex!("fdeb377a4ffd7f95ebe48e5b43a88d069ce62188e49493500315ad55ee04d7442b93c4c91d5475370e9380496f4bc0b838c2483bce4e133c6f18b0adbb9e4722"),
+				hex!("439fd9495643314fa623f2581f4b3d7d6037394468084f4ad7d8031479d9d101828bedd2ad95380b11f67a05ea0a76f0c3fef2bee9f043f4dffdddde09f55c01")
+			),
+			(
+				hex!("8b9063872331dda4c3c282f7d813fb3c13e7339b7dc9635fdc764e32cc57cb15"),
+				hex!("5ebfe047f421e1a3e1d9bbb163839812657bbb3e4ffe9856a725b2b405844cf3"),
+				vec![0x0a],
+				hex!("60f32f5ad3e9694b82ccc0a735edb2f940f757ab333cc5f7b0a41158b80f574f"),
+				hex!("44f3728bc5ad550aeeb89f8db340b2fceffc946be3e2d8c5d99b47c1fce344b3c7fcee223a9b29a64fe4a86a9994784bc165bb0fba03ca0a493f75bee89a0946"),
+				hex!("8aa1c755a00a6a25bdecda197ee1b60a01e50787bd10aa976133f4c39179330e18c74ffd67e6abc658e2d05ecd3101ddc0c33623823f2395538cf8d39e654f12")
+			)
+		];
+
+		for (secret, pubkey, alpha, gamma, beta, proof) in vectors.iter() {
+			let signature = ext_bandersnatch_ietf_sign(secret, alpha, &[]);
+
+			assert_eq!(ext_bandersnatch_public(secret), pubkey.to_vec());
+			assert_eq!(signature[..32], gamma[..]);
+			assert_eq!(signature[32..], proof[..]);
+			assert_eq!(ext_bandersnatch_vrf_output(secret, alpha), gamma.to_vec());
+			assert_eq!(ext_bandersnatch_output_hash(gamma), beta.to_vec());
+			assert!(ext_bandersnatch_ietf_verify(pubkey, alpha, &[], &signature));
+		}
+	}
+
+	#[test]
+	fn can_ietf_sign_and_verify() {
+		let pair = ext_bandersnatch_from_seed(&generate_random_seed());
+		let signature = ext_bandersnatch_ietf_sign(&pair[..32], b"input", b"ad");
+
+		assert_eq!(signature.len(), KEY_SIZE + IETF_PROOF_SIZE);
+		assert!(ext_bandersnatch_ietf_verify(&pair[32..], b"input", b"ad", &signature));
+		assert!(!ext_bandersnatch_ietf_verify(&pair[32..], b"other", b"ad", &signature));
+		assert!(!ext_bandersnatch_ietf_verify(&pair[32..], b"input", b"other", &signature));
+	}
+
+	#[test]
+	fn can_ring_sign_and_verify() {
+		let ring = ext_bandersnatch_ring_new(&test_srs(), RING_SIZE as u32);
+		let (pairs, pubkeys) = ring_keys();
+		let commitment = ext_bandersnatch_ring_commitment(ring, &pubkeys);
+		let signature = ext_bandersnatch_ring_sign(ring, &pairs[3][..32], &pubkeys, 3, b"input", b"ad");
+
+		assert!(ext_bandersnatch_ring_max_size(ring) as usize >= RING_SIZE);
+		assert_eq!(signature[..32], ext_bandersnatch_vrf_output(&pairs[3][..32], b"input")[..]);
+		assert!(ext_bandersnatch_ring_verify(ring, &signature, &commitment, b"input", b"ad"));
+		assert!(!ext_bandersnatch_ring_verify(ring, &signature, &commitment, b"other", b"ad"));
+		assert!(!ext_bandersnatch_ring_verify(ring, &signature, &commitment, b"input", b"other"));
+		assert!(ext_bandersnatch_ring_free(ring));
+		assert!(!ext_bandersnatch_ring_free(ring));
+	}
+
+	#[test]
+	fn ring_verify_fails_on_other_ring() {
+		let ring = ext_bandersnatch_ring_new(&test_srs(), RING_SIZE as u32);
+		let (pairs, pubkeys) = ring_keys();
+		let (_, others) = ring_keys();
+		let signature = ext_bandersnatch_ring_sign(ring, &pairs[0][..32], &pubkeys, 0, b"input", b"");
+
+		assert!(!ext_bandersnatch_ring_verify(ring, &signature, &ext_bandersnatch_ring_commitment(ring, &others), b"input", b""));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid index provided.")]
+	fn ring_sign_fails_on_wrong_index() {
+		let ring = ext_bandersnatch_ring_new(&test_srs(), RING_SIZE as u32);
+		let (pairs, pubkeys) = ring_keys();
+
+		ext_bandersnatch_ring_sign(ring, &pairs[0][..32], &pubkeys, 1, b"input", b"");
+	}
+}
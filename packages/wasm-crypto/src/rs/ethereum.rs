@@ -0,0 +1,629 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use secp256k1::{ecdsa::{RecoverableSignature, RecoveryId}, Message, PublicKey, SecretKey, SECP256K1};
+use serde_json::{Map, Value};
+use wasm_bindgen::prelude::*;
+
+use crate::hashing::ext_keccak256;
+
+/// Size of an Ethereum address, bytes
+pub const ADDRESS_SIZE: usize = 20;
+
+/// Keccak256 helper, returning a fixed-size array
+fn keccak256(data: &[u8]) -> [u8; 32] {
+	let mut res = [0u8; 32];
+
+	res.copy_from_slice(&ext_keccak256(data));
+
+	res
+}
+
+/// Decode a hex string, with or without the 0x prefix
+fn hex_decode(value: &str) -> Option<Vec<u8>> {
+	hex_decode_unprefixed(value.strip_prefix("0x").unwrap_or(value))
+}
+
+/// Decode a hex string without a prefix
+fn hex_decode_unprefixed(value: &str) -> Option<Vec<u8>> {
+	if value.len() % 2 != 0 {
+		return None;
+	}
+
+	(0..value.len())
+		.step_by(2)
+		.map(|i| value.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+		.collect()
+}
+
+/// Create an address from a public key
+///
+/// Accepts compressed (33 bytes), uncompressed (65 bytes) or raw x || y (64 bytes) keys
+fn address_from_pubkey(pubkey: &[u8]) -> Option<[u8; ADDRESS_SIZE]> {
+	let key = match pubkey.len() {
+		64 => PublicKey::from_slice(&[&[0x04], pubkey].concat()),
+		_ => PublicKey::from_slice(pubkey)
+	};
+
+	match key {
+		Ok(k) => {
+			let mut res = [0u8; ADDRESS_SIZE];
+
+			// the hash is over the uncompressed key, without the 0x04 prefix
+			res.copy_from_slice(&keccak256(&k.serialize_uncompressed()[1..])[12..]);
+
+			Some(res)
+		},
+		_ => None
+	}
+}
+
+/// EIP-55 checksummed representation of an address, including the 0x prefix
+fn address_checksum(address: &[u8]) -> String {
+	let lower = address
+		.iter()
+		.fold(String::new(), |hex, b| hex + &format!("{:02x}", b));
+	let hash = keccak256(lower.as_bytes());
+	let mut res = String::from("0x");
+
+	for (i, c) in lower.chars().enumerate() {
+		let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+
+		res.push(if nibble >= 8 { c.to_ascii_uppercase() } else { c });
+	}
+
+	res
+}
+
+/// EIP-191 personal_sign hash, keccak256("\x19Ethereum Signed Message:\n" + len(message) + message)
+fn personal_hash(message: &[u8]) -> [u8; 32] {
+	let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+
+	data.extend_from_slice(message);
+
+	keccak256(&data)
+}
+
+/// Split a type into the base type and array dimension, e.g. Person[2] -> (Person, Some(Some(2)))
+fn split_array(kind: &str) -> Option<(&str, Option<usize>)> {
+	match kind.strip_suffix(']') {
+		Some(k) => k
+			.rfind('[')
+			.and_then(|i| match &k[i + 1..] {
+				"" => Some((&k[..i], None)),
+				n => n.parse().ok().map(|n| (&k[..i], Some(n)))
+			}),
+		_ => None
+	}
+}
+
+/// EIP-712 typed data encoding
+struct TypedData<'a> {
+	types: &'a Map<String, Value>,
+}
+
+impl<'a> TypedData<'a> {
+	/// The list of (name, type) pairs for a struct type
+	fn fields(&self, kind: &str) -> Result<Vec<(&'a str, &'a str)>, &'static str> {
+		self.types
+			.get(kind)
+			.and_then(|f| f.as_array())
+			.ok_or("Unknown struct type.")?
+			.iter()
+			.map(|f| match (f.get("name").and_then(|n| n.as_str()), f.get("type").and_then(|t| t.as_str())) {
+				(Some(n), Some(t)) => Ok((n, t)),
+				_ => Err("Invalid struct field.")
+			})
+			.collect()
+	}
+
+	/// Recursively collect all struct types referenced by a type
+	fn dependencies(&self, kind: &'a str, found: &mut Vec<&'a str>) -> Result<(), &'static str> {
+		let base = match split_array(kind) {
+			Some((b, _)) => return self.dependencies(b, found),
+			_ => kind
+		};
+
+		if found.contains(&base) || !self.types.contains_key(base) {
+			return Ok(());
+		}
+
+		found.push(base);
+
+		for (_, t) in self.fields(base)? {
+			self.dependencies(t, found)?;
+		}
+
+		Ok(())
+	}
+
+	/// encodeType, the primary type followed by the sorted referenced types
+	fn encode_type(&self, kind: &'a str) -> Result<String, &'static str> {
+		let mut deps = vec![];
+
+		self.dependencies(kind, &mut deps)?;
+		deps.retain(|d| *d != kind);
+		deps.sort_unstable();
+		deps.insert(0, kind);
+
+		deps
+			.iter()
+			.map(|d| Ok(format!(
+				"{}({})",
+				d,
+				self.fields(d)?
+					.iter()
+					.map(|(n, t)| format!("{} {}", t, n))
+					.collect::<Vec<_>>()
+					.join(",")
+			)))
+			.collect()
+	}
+
+	/// hashStruct, keccak256(typeHash || encodeData(value))
+	fn hash_struct(&self, kind: &'a str, value: &Value) -> Result<[u8; 32], &'static str> {
+		let value = value.as_object().ok_or("Invalid struct value.")?;
+		let mut data = keccak256(self.encode_type(kind)?.as_bytes()).to_vec();
+
+		for (n, t) in self.fields(kind)? {
+			data.extend_from_slice(&self.encode_value(t, value.get(n).ok_or("Missing struct field.")?)?);
+		}
+
+		Ok(keccak256(&data))
+	}
+
+	/// Encode a single value as its 32-byte representation
+	fn encode_value(&self, kind: &'a str, value: &Value) -> Result<[u8; 32], &'static str> {
+		if let Some((base, size)) = split_array(kind) {
+			let items = value.as_array().ok_or("Invalid array value.")?;
+			let mut data = vec![];
+
+			if size.map_or(false, |s| s != items.len()) {
+				return Err("Invalid array length.");
+			}
+
+			for i in items {
+				data.extend_from_slice(&self.encode_value(base, i)?);
+			}
+
+			return Ok(keccak256(&data));
+		}
+
+		if self.types.contains_key(kind) {
+			return self.hash_struct(kind, value);
+		}
+
+		let mut res = [0u8; 32];
+
+		match kind {
+			"string" => res = keccak256(value.as_str().ok_or("Invalid string value.")?.as_bytes()),
+			"bytes" => res = keccak256(&value.as_str().and_then(hex_decode).ok_or("Invalid bytes value.")?),
+			"bool" => res[31] = value.as_bool().ok_or("Invalid bool value.")? as u8,
+			"address" => {
+				let address = value.as_str().and_then(hex_decode).ok_or("Invalid address value.")?;
+
+				if address.len() != ADDRESS_SIZE {
+					return Err("Invalid address value.");
+				}
+
+				res[32 - ADDRESS_SIZE..].copy_from_slice(&address);
+			},
+			_ => if let Some(size) = kind.strip_prefix("bytes") {
+				let bytes = value.as_str().and_then(hex_decode).ok_or("Invalid bytes value.")?;
+
+				if size.parse::<usize>().map_or(true, |s| s == 0 || s > 32 || bytes.len() > s) {
+					return Err("Invalid bytes value.");
+				}
+
+				res[..bytes.len()].copy_from_slice(&bytes);
+			} else if let Some((signed, bits)) = integer_type(kind) {
+				res = encode_integer(value, signed, bits)?;
+			} else {
+				return Err("Unknown type.");
+			}
+		}
+
+		Ok(res)
+	}
+}
+
+/// Split an intN/uintN type into the signedness and bit width, e.g. uint8 -> (false, 8)
+///
+/// The width defaults to 256 when not specified and has to be a multiple of 8 in 8..=256
+fn integer_type(kind: &str) -> Option<(bool, usize)> {
+	let (signed, bits) = match kind.strip_prefix("uint") {
+		Some(b) => (false, b),
+		_ => (true, kind.strip_prefix("int")?)
+	};
+
+	match bits {
+		"" => Some((signed, 256)),
+		b if b.starts_with('0') => None,
+		b => b.parse().ok().filter(|b| b % 8 == 0 && (8..=256).contains(b)).map(|b| (signed, b))
+	}
+}
+
+/// Encode an integer (JSON number or decimal/hex string) as a 32-byte two's complement value
+///
+/// The value is range-checked against the bit width of the intN/uintN type
+fn encode_integer(value: &Value, signed: bool, bits: usize) -> Result<[u8; 32], &'static str> {
+	let (negative, digits) = match value {
+		Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+			(Some(u), _) => (false, u.to_string()),
+			(_, Some(i)) => (true, i.unsigned_abs().to_string()),
+			_ => return Err("Invalid integer value.")
+		},
+		Value::String(s) => match s.strip_prefix('-') {
+			Some(s) => (true, s.to_string()),
+			_ => (false, s.to_string())
+		},
+		_ => return Err("Invalid integer value.")
+	};
+
+	if negative && !signed {
+		return Err("Invalid integer value.");
+	}
+
+	let (radix, digits) = match digits.strip_prefix("0x") {
+		Some(d) => (16, d),
+		_ => (10, digits.as_str())
+	};
+	let mut res = [0u8; 32];
+
+	if digits.is_empty() {
+		return Err("Invalid integer value.");
+	}
+
+	for c in digits.chars() {
+		let mut carry = c.to_digit(radix).ok_or("Invalid integer value.")?;
+
+		for b in res.iter_mut().rev() {
+			let v = (*b as u32) * radix + carry;
+
+			*b = v as u8;
+			carry = v >> 8;
+		}
+
+		if carry != 0 {
+			return Err("Integer value overflow.");
+		}
+	}
+
+	// the magnitude has to be below 2^bits (unsigned), 2^(bits-1) (positive) or at most 2^(bits-1) (negative)
+	let top = 32 - bits / 8;
+	let upper = res[..top].iter().any(|b| *b != 0);
+	let fits = match (signed, negative) {
+		(false, _) => !upper,
+		(true, false) => !upper && res[top] < 0x80,
+		(true, true) => !upper && (res[top] < 0x80 || (res[top] == 0x80 && res[top + 1..].iter().all(|b| *b == 0)))
+	};
+
+	if !fits {
+		return Err("Integer value overflow.");
+	}
+
+	if negative {
+		let mut carry = 1u16;
+
+		for b in res.iter_mut().rev() {
+			let v = (!*b as u16) + carry;
+
+			*b = v as u8;
+			carry = v >> 8;
+		}
+	}
+
+	Ok(res)
+}
+
+/// EIP-712 signing hash, keccak256(0x1901 || domainSeparator || hashStruct(message))
+fn typed_data_hash(json: &str) -> Result<[u8; 32], &'static str> {
+	let data: Value = serde_json::from_str(json).map_err(|_| "Invalid JSON.")?;
+	let typed = TypedData {
+		types: data.get("types").and_then(|t| t.as_object()).ok_or("Invalid types.")?
+	};
+	let primary = data.get("primaryType").and_then(|p| p.as_str()).ok_or("Invalid primaryType.")?;
+	let mut res = vec![0x19, 0x01];
+
+	res.extend_from_slice(&typed.hash_struct("EIP712Domain", data.get("domain").ok_or("Invalid domain.")?)?);
+
+	// as per the spec, the message is omitted when the domain is the primary type
+	if primary != "EIP712Domain" {
+		res.extend_from_slice(&typed.hash_struct(primary, data.get("message").ok_or("Invalid message.")?)?);
+	}
+
+	Ok(keccak256(&res))
+}
+
+/// Create an Ethereum address from a public key
+///
+/// * pubkey: UIntArray with 33 (compressed), 64 (raw x || y) or 65 (uncompressed) element
+///
+/// returned vector is the 20-byte address
+#[wasm_bindgen]
+pub fn ext_eth_address(pubkey: &[u8]) -> Vec<u8> {
+	match address_from_pubkey(pubkey) {
+		Some(a) => a.to_vec(),
+		_ => panic!("Invalid pubkey provided.")
+	}
+}
+
+/// Format an address with the EIP-55 mixed-case checksum
+///
+/// * address: UIntArray with 20 element
+///
+/// Returns the 0x-prefixed checksummed address
+#[wasm_bindgen]
+pub fn ext_eth_address_checksum(address: &[u8]) -> String {
+	match address.len() {
+		ADDRESS_SIZE => address_checksum(address),
+		_ => panic!("Invalid address provided.")
+	}
+}
+
+/// Validate a hex address against the EIP-55 checksum
+///
+/// All-lowercase and all-uppercase addresses carry no checksum and are accepted.
+///
+/// * address: 0x-prefixed hex address
+#[wasm_bindgen]
+pub fn ext_eth_address_validate(address: &str) -> bool {
+	let hex = match address.strip_prefix("0x") {
+		Some(h) => h,
+		_ => return false
+	};
+
+	match hex_decode_unprefixed(hex) {
+		Some(a) if a.len() == ADDRESS_SIZE => {
+			hex == hex.to_ascii_lowercase() || hex == hex.to_ascii_uppercase() || address == address_checksum(&a)
+		},
+		_ => false
+	}
+}
+
+/// Sign a message with the EIP-191 personal_sign prefix
+///
+/// * message: Arbitrary length UIntArray
+/// * seckey: UIntArray with 32 element
+///
+/// returned vector is the 65-byte r || s || v signature, with v as 27 or 28
+#[wasm_bindgen]
+pub fn ext_eth_personal_sign(message: &[u8], seckey: &[u8]) -> Vec<u8> {
+	match SecretKey::from_slice(seckey) {
+		Ok(s) => {
+			let mut res = vec![];
+			let (rec, sig) = SECP256K1
+				.sign_ecdsa_recoverable(&Message::from_slice(&personal_hash(message)).unwrap(), &s)
+				.serialize_compact();
+
+			res.extend_from_slice(&sig);
+			res.push(27 + rec.to_i32() as u8);
+
+			res
+		},
+		_ => panic!("Invalid secret provided.")
+	}
+}
+
+/// Recover the signer address of an EIP-191 personal_sign message
+///
+/// * message: Arbitrary length UIntArray
+/// * sig: UIntArray with 65 element, with v as 0, 1, 27 or 28
+///
+/// returned vector is the 20-byte address
+#[wasm_bindgen]
+pub fn ext_eth_personal_recover(message: &[u8], sig: &[u8]) -> Vec<u8> {
+	if sig.len() != 65 {
+		panic!("Invalid signature provided.");
+	}
+
+	let rec = match sig[64] {
+		v @ 27..=28 => v - 27,
+		v => v
+	};
+
+	match RecoveryId::from_i32(rec as i32) {
+		Ok(r) => match (Message::from_slice(&personal_hash(message)), RecoverableSignature::from_compact(&sig[..64], r)) {
+			(Ok(m), Ok(s)) => match s.recover(&m) {
+				Ok(k) => ext_eth_address(&k.serialize()),
+				_ => panic!("Unable to recover.")
+			},
+			_ => panic!("Invalid signature provided.")
+		},
+		_ => panic!("Invalid recovery data provided.")
+	}
+}
+
+/// Create the EIP-712 signing hash for typed data
+///
+/// * json: The typed data as JSON, with types, primaryType, domain and message
+///
+/// returned vector is the 32-byte hash, as signed by eth_signTypedData_v4
+#[wasm_bindgen]
+pub fn ext_eth_typed_data_hash(json: &str) -> Vec<u8> {
+	match typed_data_hash(json) {
+		Ok(h) => h.to_vec(),
+		Err(e) => panic!("Invalid typed data provided: {}", e)
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	use hex_literal::hex;
+	use super::*;
+	use crate::secp256k1::ext_secp_from_seed;
+
+	const MAIL: &str = r#"{
+		"types": {
+			"EIP712Domain": [
+				{ "name": "name", "type": "string" },
+				{ "name": "version", "type": "string" },
+				{ "name": "chainId", "type": "uint256" },
+				{ "name": "verifyingContract", "type": "address" }
+			],
+			"Person": [
+				{ "name": "name", "type": "string" },
+				{ "name": "wallet", "type": "address" }
+			],
+			"Mail": [
+				{ "name": "from", "type": "Person" },
+				{ "name": "to", "type": "Person" },
+				{ "name": "contents", "type": "string" }
+			]
+		},
+		"primaryType": "Mail",
+		"domain": {
+			"name": "Ether Mail",
+			"version": "1",
+			"chainId": 1,
+			"verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+		},
+		"message": {
+			"from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+			"to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+			"contents": "Hello, Bob!"
+		}
+	}"#;
+
+	#[test]
+	fn can_create_address() {
+		let seckey = hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+		let expected = hex!("2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+		let pair = ext_secp_from_seed(&seckey);
+		let expanded = crate::secp256k1::ext_secp_pub_expand(&pair[32..]);
+
+		assert_eq!(ext_eth_address(&pair[32..])[..], expected[..]);
+		assert_eq!(ext_eth_address(&expanded)[..], expected[..]);
+		assert_eq!(ext_eth_address(&expanded[1..])[..], expected[..]);
+	}
+
+	#[test]
+	fn can_checksum_address() {
+		// https://eips.ethereum.org/EIPS/eip-55
+		let vectors = [
+			"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+			"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+			"0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+			"0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb"
+		];
+
+		for address in vectors.iter() {
+			assert_eq!(ext_eth_address_checksum(&hex_decode(address).unwrap()), *address);
+			assert!(ext_eth_address_validate(address));
+			assert!(ext_eth_address_validate(&address.to_ascii_lowercase()));
+		}
+	}
+
+	#[test]
+	fn can_validate_address_fail() {
+		assert!(!ext_eth_address_validate("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+		assert!(!ext_eth_address_validate("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"));
+		assert!(!ext_eth_address_validate("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+		assert!(!ext_eth_address_validate("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg"));
+		assert!(!ext_eth_address_validate("0x0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+		assert!(!ext_eth_address_validate("0x0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"));
+	}
+
+	#[test]
+	fn can_personal_sign() {
+		let seckey = hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+		let expected = hex!("b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c");
+		let sig = ext_eth_personal_sign(b"Some data", &seckey);
+
+		assert_eq!(personal_hash(b"Some data")[..], hex!("1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655")[..]);
+		assert_eq!(sig[..], expected[..]);
+	}
+
+	#[test]
+	fn can_personal_recover() {
+		let expected = hex!("2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+		let mut sig = hex!("b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c");
+
+		assert_eq!(ext_eth_personal_recover(b"Some data", &sig)[..], expected[..]);
+
+		sig[64] = 1;
+
+		assert_eq!(ext_eth_personal_recover(b"Some data", &sig)[..], expected[..]);
+		assert_ne!(ext_eth_personal_recover(b"Other data", &sig)[..], expected[..]);
+	}
+
+	#[test]
+	fn can_typed_data_hash() {
+		// https://eips.ethereum.org/EIPS/eip-712, Example.js
+		let expected = hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+		assert_eq!(ext_eth_typed_data_hash(MAIL)[..], expected[..]);
+	}
+
+	#[test]
+	fn can_encode_type() {
+		let data: Value = serde_json::from_str(MAIL).unwrap();
+		let typed = TypedData { types: data["types"].as_object().unwrap() };
+
+		assert_eq!(typed.encode_type("Mail").unwrap(), "Mail(Person from,Person to,string contents)Person(string name,address wallet)");
+		assert_eq!(
+			typed.hash_struct("EIP712Domain", &data["domain"]).unwrap()[..],
+			hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")[..]
+		);
+		assert_eq!(
+			typed.hash_struct("Mail", &data["message"]).unwrap()[..],
+			hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")[..]
+		);
+	}
+
+	#[test]
+	fn can_encode_integer() {
+		let mut minus_one = [0xffu8; 32];
+		let mut large = [0u8; 32];
+
+		large[30] = 0x01;
+		large[31] = 0x00;
+
+		assert_eq!(encode_integer(&Value::from(-1), true, 256).unwrap(), minus_one);
+		assert_eq!(encode_integer(&Value::from("-1"), true, 8).unwrap(), minus_one);
+		assert_eq!(encode_integer(&Value::from("0x100"), false, 16).unwrap(), large);
+		assert_eq!(encode_integer(&Value::from("256"), false, 256).unwrap(), large);
+		assert!(encode_integer(&Value::from(-1), false, 256).is_err());
+		assert!(encode_integer(&Value::from("0x1".to_string() + &"0".repeat(64)), false, 256).is_err());
+
+		minus_one[0] = 0x7f;
+
+		assert_eq!(encode_integer(&Value::from(format!("0x7f{}", "f".repeat(62))), false, 256).unwrap(), minus_one);
+		assert_eq!(encode_integer(&Value::from(format!("0x7f{}", "f".repeat(62))), true, 256).unwrap(), minus_one);
+	}
+
+	#[test]
+	fn can_encode_integer_range() {
+		assert!(encode_integer(&Value::from(255), false, 8).is_ok());
+		assert!(encode_integer(&Value::from(300), false, 8).is_err());
+		assert!(encode_integer(&Value::from(127), true, 8).is_ok());
+		assert!(encode_integer(&Value::from(128), true, 8).is_err());
+		assert!(encode_integer(&Value::from(-128), true, 8).is_ok());
+		assert!(encode_integer(&Value::from(-129), true, 8).is_err());
+		assert!(encode_integer(&Value::from("-1"), false, 256).is_err());
+		assert!(encode_integer(&Value::from(format!("0x8{}", "0".repeat(63))), true, 256).is_err());
+		assert!(encode_integer(&Value::from(format!("-0x8{}", "0".repeat(63))), true, 256).is_ok());
+	}
+
+	#[test]
+	fn can_parse_integer_type() {
+		assert_eq!(integer_type("uint"), Some((false, 256)));
+		assert_eq!(integer_type("uint8"), Some((false, 8)));
+		assert_eq!(integer_type("int128"), Some((true, 128)));
+		assert_eq!(integer_type("uint7"), None);
+		assert_eq!(integer_type("int264"), None);
+		assert_eq!(integer_type("uint08"), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid typed data provided: Invalid integer value.")]
+	fn typed_data_hash_fails_on_negative_unsigned() {
+		ext_eth_typed_data_hash(&MAIL.replace(r#""chainId": 1"#, r#""chainId": -1"#));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid typed data provided: Missing struct field.")]
+	fn typed_data_hash_fails_on_missing_field() {
+		ext_eth_typed_data_hash(&MAIL.replace(r#""contents": "Hello, Bob!""#, r#""other": "Hello, Bob!""#));
+	}
+}
@@ -0,0 +1,873 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{cell::RefCell, cmp::Ordering};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::{CompressedEdwardsY, EdwardsPoint}, scalar::Scalar, traits::Identity};
+use sha2::{Digest, Sha512};
+use wasm_bindgen::prelude::*;
+
+use crate::handles::{self, Handles};
+
+/// RFC 9591 contextString for FROST(Ed25519, SHA-512)
+const CONTEXT: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+/// Size of a serialized scalar or element, bytes
+pub const ELEMENT_SIZE: usize = 32;
+
+/// Size of a signing commitment (identifier, hiding and binding commitments)
+pub const COMMITMENT_SIZE: usize = 3 * ELEMENT_SIZE;
+
+/// Size of a DKG proof of knowledge (R and mu)
+pub const PROOF_SIZE: usize = 2 * ELEMENT_SIZE;
+
+/// A participant commitment, as received in the commitment list
+struct SigningCommitment {
+	identifier: Scalar,
+	hiding: EdwardsPoint,
+	binding: EdwardsPoint,
+}
+
+/// SHA-512 over the domain-separated input, H2 is used without context
+fn hash(tag: &[u8], data: &[&[u8]]) -> [u8; 64] {
+	let mut hasher = Sha512::new();
+	let mut res = [0u8; 64];
+
+	if !tag.is_empty() {
+		hasher.input(CONTEXT);
+		hasher.input(tag);
+	}
+
+	for d in data {
+		hasher.input(d);
+	}
+
+	res.copy_from_slice(&hasher.result());
+
+	res
+}
+
+/// SHA-512 over the domain-separated input, reduced to a scalar
+fn hash_to_scalar(tag: &[u8], data: &[&[u8]]) -> Scalar {
+	Scalar::from_bytes_mod_order_wide(&hash(tag, data))
+}
+
+/// Generate random bytes via the environment
+fn random_bytes<const N: usize>() -> [u8; N] {
+	let mut res = [0u8; N];
+
+	getrandom::getrandom(&mut res).expect("Unable to retrieve randomness.");
+
+	res
+}
+
+/// Generate a random non-zero scalar
+fn random_scalar() -> Scalar {
+	loop {
+		let s = Scalar::from_bytes_mod_order_wide(&random_bytes::<64>());
+
+		if s != Scalar::zero() {
+			return s;
+		}
+	}
+}
+
+/// RFC 9591 nonce_generate, hedging the supplied randomness with the secret
+fn nonce_generate(random: &[u8], secret: &Scalar) -> Scalar {
+	hash_to_scalar(b"nonce", &[random, secret.as_bytes()])
+}
+
+/// Multiply with the base point
+fn base_mul(s: &Scalar) -> EdwardsPoint {
+	&ED25519_BASEPOINT_TABLE * s
+}
+
+/// Decode a canonical scalar
+fn decode_scalar(bytes: &[u8]) -> Option<Scalar> {
+	let mut s = [0u8; ELEMENT_SIZE];
+
+	match bytes.len() {
+		ELEMENT_SIZE => {
+			s.copy_from_slice(bytes);
+
+			Scalar::from_canonical_bytes(s)
+		},
+		_ => None
+	}
+}
+
+/// Decode an element, rejecting the identity and elements outside the prime-order subgroup
+fn decode_point(bytes: &[u8]) -> Option<EdwardsPoint> {
+	if bytes.len() != ELEMENT_SIZE {
+		return None;
+	}
+
+	match CompressedEdwardsY::from_slice(bytes).decompress() {
+		Some(p) if p != EdwardsPoint::identity() && p.is_torsion_free() && p.compress().as_bytes()[..] == bytes[..] => Some(p),
+		_ => None
+	}
+}
+
+/// Decode a list of concatenated elements, e.g. a VSS commitment
+fn decode_points(bytes: &[u8]) -> Option<Vec<EdwardsPoint>> {
+	if bytes.is_empty() || bytes.len() % ELEMENT_SIZE != 0 {
+		return None;
+	}
+
+	bytes
+		.chunks(ELEMENT_SIZE)
+		.map(decode_point)
+		.collect()
+}
+
+/// Decode the sorted commitment list, identifier || hiding || binding for each participant
+fn decode_commitments(bytes: &[u8]) -> Option<Vec<SigningCommitment>> {
+	if bytes.is_empty() || bytes.len() % COMMITMENT_SIZE != 0 {
+		return None;
+	}
+
+	let list = bytes
+		.chunks(COMMITMENT_SIZE)
+		.map(|c| match (decode_scalar(&c[..ELEMENT_SIZE]), decode_point(&c[ELEMENT_SIZE..2 * ELEMENT_SIZE]), decode_point(&c[2 * ELEMENT_SIZE..])) {
+			(Some(identifier), Some(hiding), Some(binding)) if identifier != Scalar::zero() => Some(SigningCommitment { identifier, hiding, binding }),
+			_ => None
+		})
+		.collect::<Option<Vec<SigningCommitment>>>()?;
+
+	// the list needs to be sorted by identifier, without duplicates
+	match list.windows(2).all(|w| compare_scalars(&w[0].identifier, &w[1].identifier) == Ordering::Less) {
+		true => Some(list),
+		false => None
+	}
+}
+
+/// Compare scalars as (little-endian encoded) integers
+fn compare_scalars(a: &Scalar, b: &Scalar) -> Ordering {
+	a.as_bytes().iter().rev().cmp(b.as_bytes().iter().rev())
+}
+
+/// Map a participant identifier to a scalar, identifiers start at 1
+fn identifier_scalar(identifier: u16) -> Scalar {
+	Scalar::from(identifier as u64)
+}
+
+/// Evaluate the polynomial with the given coefficients at x
+fn polynomial_evaluate(x: &Scalar, coefficients: &[Scalar]) -> Scalar {
+	coefficients
+		.iter()
+		.rev()
+		.fold(Scalar::zero(), |value, c| value * x + c)
+}
+
+/// Evaluate the polynomial commitment at x, i.e. the public counterpart of polynomial_evaluate
+fn commitment_evaluate(x: &Scalar, commitment: &[EdwardsPoint]) -> EdwardsPoint {
+	commitment
+		.iter()
+		.rev()
+		.fold(EdwardsPoint::identity(), |value, c| value * x + c)
+}
+
+/// RFC 9591 derive_interpolating_value, the Lagrange coefficient for x_i
+fn derive_interpolating_value(list: &[Scalar], x_i: &Scalar) -> Scalar {
+	let (numerator, denominator) = list
+		.iter()
+		.filter(|x_j| *x_j != x_i)
+		.fold((Scalar::one(), Scalar::one()), |(n, d), x_j| (n * x_j, d * (x_j - x_i)));
+
+	numerator * denominator.invert()
+}
+
+/// RFC 9591 compute_binding_factors, one for each entry in the commitment list
+fn compute_binding_factors(pubkey: &EdwardsPoint, commitments: &[SigningCommitment], encoded: &[u8], message: &[u8]) -> Vec<Scalar> {
+	let msg_hash = hash(b"msg", &[message]);
+	let com_hash = hash(b"com", &[encoded]);
+	let pubkey = pubkey.compress();
+
+	commitments
+		.iter()
+		.map(|c| hash_to_scalar(b"rho", &[pubkey.as_bytes(), &msg_hash, &com_hash, c.identifier.as_bytes()]))
+		.collect()
+}
+
+/// RFC 9591 compute_group_commitment
+fn compute_group_commitment(commitments: &[SigningCommitment], factors: &[Scalar]) -> EdwardsPoint {
+	commitments
+		.iter()
+		.zip(factors.iter())
+		.fold(EdwardsPoint::identity(), |r, (c, f)| r + c.hiding + c.binding * f)
+}
+
+/// RFC 9591 compute_challenge, compatible with the ed25519 challenge
+fn compute_challenge(group_commitment: &EdwardsPoint, pubkey: &EdwardsPoint, message: &[u8]) -> Scalar {
+	hash_to_scalar(b"", &[group_commitment.compress().as_bytes(), pubkey.compress().as_bytes(), message])
+}
+
+/// The binding factors, group commitment and challenge for a signing set
+struct SigningValues {
+	commitments: Vec<SigningCommitment>,
+	factors: Vec<Scalar>,
+	identifiers: Vec<Scalar>,
+	challenge: Scalar,
+	group_commitment: EdwardsPoint,
+}
+
+impl SigningValues {
+	fn new(pubkey: &EdwardsPoint, encoded: &[u8], message: &[u8]) -> Option<SigningValues> {
+		let commitments = decode_commitments(encoded)?;
+		let factors = compute_binding_factors(pubkey, &commitments, encoded, message);
+		let group_commitment = compute_group_commitment(&commitments, &factors);
+		let challenge = compute_challenge(&group_commitment, pubkey, message);
+		let identifiers = commitments
+			.iter()
+			.map(|c| c.identifier)
+			.collect();
+
+		Some(SigningValues { commitments, factors, identifiers, challenge, group_commitment })
+	}
+
+	/// Position of the participant in the commitment list
+	fn position(&self, identifier: &Scalar) -> Option<usize> {
+		self.commitments
+			.iter()
+			.position(|c| c.identifier == *identifier)
+	}
+}
+
+/// Generate the secret shares via a trusted dealer
+///
+/// * secret: UIntArray with 32 element, the canonical group secret scalar (or empty to generate one)
+/// * max: the number of participants
+/// * min: the signing threshold, 2 <= min <= max
+///
+/// returned vector is the VSS commitment (32 * min bytes, with the group public key as
+/// first element) followed by the secret shares of participants 1..=max (32 bytes each)
+#[wasm_bindgen]
+pub fn ext_frost_dealer_keygen(secret: &[u8], max: u16, min: u16) -> Vec<u8> {
+	if min < 2 || min > max {
+		panic!("Invalid threshold provided.");
+	}
+
+	let secret = match secret.len() {
+		0 => random_scalar(),
+		_ => match decode_scalar(secret) {
+			Some(s) if s != Scalar::zero() => s,
+			_ => panic!("Invalid secret provided.")
+		}
+	};
+	let coefficients: Vec<Scalar> = std::iter::once(secret)
+		.chain((1..min).map(|_| random_scalar()))
+		.collect();
+	let commitment = coefficients
+		.iter()
+		.flat_map(|c| base_mul(c).compress().to_bytes().to_vec());
+	let shares = (1..=max).flat_map(|i| polynomial_evaluate(&identifier_scalar(i), &coefficients).to_bytes().to_vec());
+
+	commitment
+		.chain(shares)
+		.collect()
+}
+
+/// Verify a secret share against the VSS commitment
+///
+/// * identifier: the participant identifier, starting at 1
+/// * share: UIntArray with 32 element
+/// * commitment: UIntArray with 32 * min elements
+#[wasm_bindgen]
+pub fn ext_frost_vss_verify(identifier: u16, share: &[u8], commitment: &[u8]) -> bool {
+	match (identifier, decode_scalar(share), decode_points(commitment)) {
+		(1..=u16::MAX, Some(s), Some(c)) => base_mul(&s) == commitment_evaluate(&identifier_scalar(identifier), &c),
+		_ => false
+	}
+}
+
+/// Derive the public verification share of a participant from the VSS commitment
+///
+/// * identifier: the participant identifier, starting at 1
+/// * commitment: UIntArray with 32 * min elements
+///
+/// returned vector is the verification share of 32 bytes
+#[wasm_bindgen]
+pub fn ext_frost_pubkey_share(identifier: u16, commitment: &[u8]) -> Vec<u8> {
+	match (identifier, decode_points(commitment)) {
+		(1..=u16::MAX, Some(c)) => commitment_evaluate(&identifier_scalar(identifier), &c)
+			.compress()
+			.to_bytes()
+			.to_vec(),
+		_ => panic!("Invalid commitment provided.")
+	}
+}
+
+/// Verify a signature share, as done by the coordinator before aggregation
+///
+/// * identifier: the participant identifier, starting at 1
+/// * pubkey_share: UIntArray with 32 element, the participant verification share
+/// * sig_share: UIntArray with 32 element
+/// * message: Arbitrary length UIntArray
+/// * commitments: UIntArray with 96 * n elements, the sorted commitment list
+/// * pubkey: UIntArray with 32 element, the group public key
+#[wasm_bindgen]
+pub fn ext_frost_verify_share(identifier: u16, pubkey_share: &[u8], sig_share: &[u8], message: &[u8], commitments: &[u8], pubkey: &[u8]) -> bool {
+	let (pubkey_share, sig_share, pubkey) = match (decode_point(pubkey_share), decode_scalar(sig_share), decode_point(pubkey)) {
+		(Some(k), Some(s), Some(p)) => (k, s, p),
+		_ => return false
+	};
+	let values = match SigningValues::new(&pubkey, commitments, message) {
+		Some(v) => v,
+		_ => return false
+	};
+	let identifier = identifier_scalar(identifier);
+
+	match values.position(&identifier) {
+		Some(i) => {
+			let c = &values.commitments[i];
+			let lambda = derive_interpolating_value(&values.identifiers, &identifier);
+
+			base_mul(&sig_share) == c.hiding + c.binding * values.factors[i] + pubkey_share * (values.challenge * lambda)
+		},
+		_ => false
+	}
+}
+
+/// Aggregate the signature shares into an ed25519 signature
+///
+/// * message: Arbitrary length UIntArray
+/// * commitments: UIntArray with 96 * n elements, the sorted commitment list
+/// * sig_shares: UIntArray with 32 * n elements, in the order of the commitment list
+/// * pubkey: UIntArray with 32 element, the group public key
+///
+/// returned vector is the signature of 64 bytes, verifiable via ext_ed_verify
+#[wasm_bindgen]
+pub fn ext_frost_aggregate(message: &[u8], commitments: &[u8], sig_shares: &[u8], pubkey: &[u8]) -> Vec<u8> {
+	let values = match decode_point(pubkey).and_then(|p| SigningValues::new(&p, commitments, message)) {
+		Some(v) => v,
+		_ => panic!("Invalid commitments provided.")
+	};
+
+	if sig_shares.len() != values.commitments.len() * ELEMENT_SIZE {
+		panic!("Invalid shares provided.");
+	}
+
+	let z = sig_shares
+		.chunks(ELEMENT_SIZE)
+		.fold(Scalar::zero(), |z, s| match decode_scalar(s) {
+			Some(s) => z + s,
+			_ => panic!("Invalid shares provided.")
+		});
+
+	[values.group_commitment.compress().to_bytes(), z.to_bytes()].concat()
+}
+
+/// Participant state for the distributed key generation
+///
+/// All participants 1..=max take part, each broadcasts its package (round 1) and
+/// sends each other participant its share (round 2) before calling finish.
+struct FrostDkg {
+	identifier: u16,
+	max: u16,
+	coefficients: Vec<Scalar>,
+}
+
+/// The DKG proof of knowledge challenge
+fn dkg_challenge(identifier: &Scalar, verifying_key: &EdwardsPoint, r: &EdwardsPoint) -> Scalar {
+	hash_to_scalar(b"dkg", &[identifier.as_bytes(), verifying_key.compress().as_bytes(), r.compress().as_bytes()])
+}
+
+impl FrostDkg {
+	/// Create the participant state with a random secret polynomial
+	///
+	/// * identifier: the participant identifier, 1 <= identifier <= max
+	/// * max: the number of participants
+	/// * min: the signing threshold, 2 <= min <= max
+	fn new(identifier: u16, max: u16, min: u16) -> FrostDkg {
+		if min < 2 || min > max {
+			panic!("Invalid threshold provided.");
+		} else if identifier == 0 || identifier > max {
+			panic!("Invalid identifier provided.");
+		}
+
+		FrostDkg {
+			identifier,
+			max,
+			coefficients: (0..min).map(|_| random_scalar()).collect(),
+		}
+	}
+
+	/// The round 1 package to broadcast to all participants
+	///
+	/// returned vector is the commitment (32 * min bytes) followed by the
+	/// proof of knowledge of the secret (64 bytes)
+	fn package(&self) -> Vec<u8> {
+		let commitment: Vec<EdwardsPoint> = self.coefficients
+			.iter()
+			.map(base_mul)
+			.collect();
+		let k = random_scalar();
+		let r = base_mul(&k);
+		let c = dkg_challenge(&identifier_scalar(self.identifier), &commitment[0], &r);
+		let mu = k + self.coefficients[0] * c;
+
+		commitment
+			.iter()
+			.flat_map(|p| p.compress().to_bytes().to_vec())
+			.chain(r.compress().to_bytes().iter().copied())
+			.chain(mu.to_bytes().iter().copied())
+			.collect()
+	}
+
+	/// The round 2 secret share to send (privately) to a participant
+	///
+	/// * identifier: the receiving participant identifier
+	///
+	/// returned vector is the share of 32 bytes
+	fn share(&self, identifier: u16) -> Vec<u8> {
+		if identifier == 0 || identifier > self.max {
+			panic!("Invalid identifier provided.");
+		}
+
+		polynomial_evaluate(&identifier_scalar(identifier), &self.coefficients)
+			.to_bytes()
+			.to_vec()
+	}
+
+	/// Verify all packages and received shares, creating the signing share
+	///
+	/// * packages: UIntArray with the round 1 packages of participants 1..=max
+	/// * shares: UIntArray with 32 * max elements, the shares received from participants 1..=max
+	///
+	/// returned vector is the signing share (32 bytes) followed by the group
+	/// VSS commitment (32 * min bytes, with the group public key as first element)
+	fn finish(&self, packages: &[u8], shares: &[u8]) -> Vec<u8> {
+		let min = self.coefficients.len();
+		let size = min * ELEMENT_SIZE + PROOF_SIZE;
+
+		if packages.len() != self.max as usize * size {
+			panic!("Invalid packages provided.");
+		} else if shares.len() != self.max as usize * ELEMENT_SIZE {
+			panic!("Invalid shares provided.");
+		}
+
+		let own = identifier_scalar(self.identifier);
+		let mut signing_share = Scalar::zero();
+		let mut group_commitment = vec![EdwardsPoint::identity(); min];
+
+		for (i, (package, share)) in packages.chunks(size).zip(shares.chunks(ELEMENT_SIZE)).enumerate() {
+			let (commitment, proof) = package.split_at(min * ELEMENT_SIZE);
+			let (commitment, r, mu) = match (decode_points(commitment), decode_point(&proof[..ELEMENT_SIZE]), decode_scalar(&proof[ELEMENT_SIZE..])) {
+				(Some(c), Some(r), Some(mu)) => (c, r, mu),
+				_ => panic!("Invalid packages provided.")
+			};
+			let c = dkg_challenge(&identifier_scalar(i as u16 + 1), &commitment[0], &r);
+
+			if r != base_mul(&mu) - commitment[0] * c {
+				panic!("Invalid packages provided.");
+			}
+
+			let share = match decode_scalar(share) {
+				Some(s) if base_mul(&s) == commitment_evaluate(&own, &commitment) => s,
+				_ => panic!("Invalid shares provided.")
+			};
+
+			signing_share += share;
+
+			for (g, c) in group_commitment.iter_mut().zip(commitment.iter()) {
+				*g += c;
+			}
+		}
+
+		signing_share
+			.to_bytes()
+			.iter()
+			.copied()
+			.chain(group_commitment.iter().flat_map(|p| p.compress().to_bytes().to_vec()))
+			.collect()
+	}
+}
+
+/// Participant state for the two signing rounds
+///
+/// The nonces generated in commit are held internally and are cleared on
+/// the first call to sign, so they can never be used for two signatures.
+struct FrostSigner {
+	identifier: u16,
+	share: Scalar,
+	pubkey: EdwardsPoint,
+	nonces: Option<(Scalar, Scalar)>,
+}
+
+impl FrostSigner {
+	/// Create the signer state
+	///
+	/// * identifier: the participant identifier, starting at 1
+	/// * share: UIntArray with 32 element, the signing share
+	/// * pubkey: UIntArray with 32 element, the group public key
+	fn new(identifier: u16, share: &[u8], pubkey: &[u8]) -> FrostSigner {
+		match (identifier, decode_scalar(share), decode_point(pubkey)) {
+			(1..=u16::MAX, Some(share), Some(pubkey)) => FrostSigner { identifier, share, pubkey, nonces: None },
+			_ => panic!("Invalid signer provided.")
+		}
+	}
+
+	/// Round 1, generate the nonces and commitments
+	///
+	/// returned vector is the commitment list entry of 96 bytes, the identifier
+	/// followed by the hiding and binding nonce commitments, replacing any
+	/// previously generated (unused) nonces
+	fn commit(&mut self) -> Vec<u8> {
+		let hiding = nonce_generate(&random_bytes::<32>(), &self.share);
+		let binding = nonce_generate(&random_bytes::<32>(), &self.share);
+
+		self.nonces = Some((hiding, binding));
+
+		[identifier_scalar(self.identifier).to_bytes(), base_mul(&hiding).compress().to_bytes(), base_mul(&binding).compress().to_bytes()].concat()
+	}
+
+	/// Round 2, create the signature share, consuming the nonces
+	///
+	/// * message: Arbitrary length UIntArray
+	/// * commitments: UIntArray with 96 * n elements, the commitment list entries sorted by identifier
+	///
+	/// returned vector is the signature share of 32 bytes
+	fn sign(&mut self, message: &[u8], commitments: &[u8]) -> Vec<u8> {
+		// take the nonces before anything else, they are never available again
+		let (hiding, binding) = match self.nonces.take() {
+			Some(n) => n,
+			_ => panic!("No unused nonce available.")
+		};
+		let values = match SigningValues::new(&self.pubkey, commitments, message) {
+			Some(v) => v,
+			_ => panic!("Invalid commitments provided.")
+		};
+		let identifier = identifier_scalar(self.identifier);
+		let i = match values.position(&identifier) {
+			Some(i) if values.commitments[i].hiding == base_mul(&hiding) && values.commitments[i].binding == base_mul(&binding) => i,
+			_ => panic!("Invalid commitments provided.")
+		};
+		let lambda = derive_interpolating_value(&values.identifiers, &identifier);
+
+		(hiding + binding * values.factors[i] + lambda * self.share * values.challenge)
+			.to_bytes()
+			.to_vec()
+	}
+}
+
+thread_local! {
+	static DKGS: RefCell<Handles<FrostDkg>> = RefCell::new(Handles::new());
+	static SIGNERS: RefCell<Handles<FrostSigner>> = RefCell::new(Handles::new());
+}
+
+/// Create the DKG participant state with a random secret polynomial
+///
+/// * identifier: the participant identifier, 1 <= identifier <= max
+/// * max: the number of participants
+/// * min: the signing threshold, 2 <= min <= max
+///
+/// returned value is the participant handle
+#[wasm_bindgen]
+pub fn ext_frost_dkg_new(identifier: u16, max: u16, min: u16) -> u32 {
+	handles::insert(&DKGS, FrostDkg::new(identifier, max, min))
+}
+
+/// The DKG round 1 package to broadcast to all participants
+///
+/// * handle: the participant handle
+///
+/// returned vector is the commitment (32 * min bytes) followed by the
+/// proof of knowledge of the secret (64 bytes)
+#[wasm_bindgen]
+pub fn ext_frost_dkg_package(handle: u32) -> Vec<u8> {
+	handles::with(&DKGS, handle, |d| d.package())
+}
+
+/// The DKG round 2 secret share to send (privately) to a participant
+///
+/// * handle: the participant handle
+/// * identifier: the receiving participant identifier
+///
+/// returned vector is the share of 32 bytes
+#[wasm_bindgen]
+pub fn ext_frost_dkg_share(handle: u32, identifier: u16) -> Vec<u8> {
+	handles::with(&DKGS, handle, |d| d.share(identifier))
+}
+
+/// Verify all DKG packages and received shares, creating the signing share
+///
+/// * handle: the participant handle
+/// * packages: UIntArray with the round 1 packages of participants 1..=max
+/// * shares: UIntArray with 32 * max elements, the shares received from participants 1..=max
+///
+/// returned vector is the signing share (32 bytes) followed by the group
+/// VSS commitment (32 * min bytes, with the group public key as first element)
+#[wasm_bindgen]
+pub fn ext_frost_dkg_finish(handle: u32, packages: &[u8], shares: &[u8]) -> Vec<u8> {
+	handles::with(&DKGS, handle, |d| d.finish(packages, shares))
+}
+
+/// Release the DKG participant state (along with the secret polynomial)
+///
+/// * handle: the participant handle
+///
+/// returns true when the handle referred to a participant
+#[wasm_bindgen]
+pub fn ext_frost_dkg_free(handle: u32) -> bool {
+	handles::remove(&DKGS, handle).is_some()
+}
+
+/// Create the signer state
+///
+/// * identifier: the participant identifier, starting at 1
+/// * share: UIntArray with 32 element, the signing share
+/// * pubkey: UIntArray with 32 element, the group public key
+///
+/// returned value is the signer handle
+#[wasm_bindgen]
+pub fn ext_frost_signer_new(identifier: u16, share: &[u8], pubkey: &[u8]) -> u32 {
+	handles::insert(&SIGNERS, FrostSigner::new(identifier, share, pubkey))
+}
+
+/// Signing round 1, generate the nonces and commitments
+///
+/// * handle: the signer handle
+///
+/// returned vector is the commitment list entry of 96 bytes, the nonces are
+/// kept in the signer and replace any previously generated (unused) nonces
+#[wasm_bindgen]
+pub fn ext_frost_signer_commit(handle: u32) -> Vec<u8> {
+	handles::with(&SIGNERS, handle, |s| s.commit())
+}
+
+/// Signing round 2, create the signature share, consuming the nonces
+///
+/// * handle: the signer handle
+/// * message: Arbitrary length UIntArray
+/// * commitments: UIntArray with 96 * n elements, the commitment list entries sorted by identifier
+///
+/// returned vector is the signature share of 32 bytes
+#[wasm_bindgen]
+pub fn ext_frost_signer_sign(handle: u32, message: &[u8], commitments: &[u8]) -> Vec<u8> {
+	handles::with(&SIGNERS, handle, |s| s.sign(message, commitments))
+}
+
+/// Release the signer state (along with any unused nonces)
+///
+/// * handle: the signer handle
+///
+/// returns true when the handle referred to a signer
+#[wasm_bindgen]
+pub fn ext_frost_signer_free(handle: u32) -> bool {
+	handles::remove(&SIGNERS, handle).is_some()
+}
+
+#[cfg(test)]
+pub mod tests {
+	use hex_literal::hex;
+	use super::*;
+	use crate::ed25519::ext_ed_verify;
+
+	fn sign_with(signers: &[u32], message: &[u8], pubkey: &[u8]) -> (Vec<u8>, Vec<u8>) {
+		let commitments: Vec<u8> = signers
+			.iter()
+			.flat_map(|s| ext_frost_signer_commit(*s))
+			.collect();
+		let shares: Vec<u8> = signers
+			.iter()
+			.flat_map(|s| ext_frost_signer_sign(*s, message, &commitments))
+			.collect();
+
+		(ext_frost_aggregate(message, &commitments, &shares, pubkey), [commitments, shares].concat())
+	}
+
+	#[test]
+	fn can_sign_rfc9591_vectors() {
+		// RFC 9591, Appendix E.1, FROST(Ed25519, SHA-512) with participants 1 & 3
+		let secret = hex!("7b1c33d3f5291d85de664833beb1ad469f7fb6025a0ec78b3a790c6e13a98304");
+		let coefficient = hex!("178199860edd8c62f5212ee91eff1295d0d670ab4ed4506866bae57e7030b204");
+		let pubkey = hex!("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673");
+		let shares = [
+			hex!("929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509"),
+			hex!("a91e66e012e4364ac9aaa405fcafd370402d9859f7b6685c07eed76bf409e80d"),
+			hex!("d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02")
+		];
+		let randomness = [
+			(hex!("0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec"), hex!("69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501")),
+			(hex!("86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f"), hex!("13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775"))
+		];
+		let nonces = [
+			(hex!("812d6104142944d5a55924de6d49940956206909f2acaeedecda2b726e630407"), hex!("b1110165fc2334149750b28dd813a39244f315cff14d4e89e6142f262ed83301")),
+			(hex!("c256de65476204095ebdc01bd11dc10e57b36bc96284595b8215222374f99c0e"), hex!("243d71944d929063bc51205714ae3c2218bd3451d0214dfb5aeec2a90c35180d"))
+		];
+		let commitments = [
+			hex!("0100000000000000000000000000000000000000000000000000000000000000b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de367e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932").to_vec(),
+			hex!("0300000000000000000000000000000000000000000000000000000000000000cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec917487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552").to_vec()
+		].concat();
+		let binding_factors = [
+			hex!("f2cb9d7dd9beff688da6fcc83fa89046b3479417f47f55600b106760eb3b5603"),
+			hex!("b087686bf35a13f3dc78e780a34b0fe8a77fef1b9938c563f5573d71d8d7890f")
+		];
+		let sig_shares = [
+			hex!("001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603"),
+			hex!("bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007")
+		];
+		let expected = hex!("36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbebd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b");
+		let message = b"test";
+		let (coefficients, group_pubkey) = match (decode_scalar(&secret), decode_scalar(&coefficient), decode_point(&pubkey)) {
+			(Some(s), Some(c), Some(p)) => ([s, c], p),
+			_ => panic!("Invalid vector provided.")
+		};
+		let commitment = [pubkey, base_mul(&coefficients[1]).compress().to_bytes()].concat();
+
+		assert_eq!(base_mul(&coefficients[0]), group_pubkey);
+
+		for (i, share) in shares.iter().enumerate() {
+			assert_eq!(polynomial_evaluate(&identifier_scalar(i as u16 + 1), &coefficients).to_bytes(), *share);
+			assert!(ext_frost_vss_verify(i as u16 + 1, share, &commitment));
+		}
+
+		let values = match SigningValues::new(&group_pubkey, &commitments, message) {
+			Some(v) => v,
+			_ => panic!("Invalid commitments provided.")
+		};
+		let mut res = vec![];
+
+		for (i, id) in [1u16, 3].iter().enumerate() {
+			// equal to the vector share, as asserted above
+			let share = polynomial_evaluate(&identifier_scalar(*id), &coefficients);
+			let hiding = nonce_generate(&randomness[i].0, &share);
+			let binding = nonce_generate(&randomness[i].1, &share);
+			let mut signer = FrostSigner::new(*id, share.as_bytes(), &pubkey);
+
+			assert_eq!((hiding.to_bytes(), binding.to_bytes()), nonces[i]);
+			assert_eq!(values.factors[i].to_bytes(), binding_factors[i]);
+
+			signer.nonces = Some((hiding, binding));
+
+			let sig_share = signer.sign(message, &commitments);
+
+			assert_eq!(sig_share[..], sig_shares[i][..]);
+			assert!(ext_frost_verify_share(*id, &ext_frost_pubkey_share(*id, &commitment), &sig_share, message, &commitments, &pubkey));
+
+			res.extend_from_slice(&sig_share);
+		}
+
+		let sig = ext_frost_aggregate(message, &commitments, &res, &pubkey);
+
+		assert_eq!(sig[..], expected[..]);
+		assert!(ext_ed_verify(&sig, message, &pubkey));
+	}
+
+	#[test]
+	fn can_dealer_keygen() {
+		let res = ext_frost_dealer_keygen(&[], 3, 2);
+		let (commitment, shares) = res.split_at(2 * ELEMENT_SIZE);
+
+		for (i, share) in shares.chunks(ELEMENT_SIZE).enumerate() {
+			assert!(ext_frost_vss_verify(i as u16 + 1, share, commitment));
+			assert!(!ext_frost_vss_verify(i as u16 + 2, share, commitment));
+		}
+	}
+
+	#[test]
+	fn can_sign_dealer() {
+		let message = b"this is a message";
+		let res = ext_frost_dealer_keygen(&[], 3, 2);
+		let (commitment, shares) = res.split_at(2 * ELEMENT_SIZE);
+		let pubkey = &commitment[..ELEMENT_SIZE];
+
+		for (a, b) in [(1u16, 2u16), (1, 3), (2, 3)].iter() {
+			let signers = [
+				ext_frost_signer_new(*a, &shares[(*a as usize - 1) * ELEMENT_SIZE..*a as usize * ELEMENT_SIZE], pubkey),
+				ext_frost_signer_new(*b, &shares[(*b as usize - 1) * ELEMENT_SIZE..*b as usize * ELEMENT_SIZE], pubkey),
+			];
+			let (sig, rest) = sign_with(&signers, message, pubkey);
+			let (commitments, sig_shares) = rest.split_at(2 * COMMITMENT_SIZE);
+
+			for (j, id) in [*a, *b].iter().enumerate() {
+				assert!(ext_frost_verify_share(*id, &ext_frost_pubkey_share(*id, commitment), &sig_shares[j * ELEMENT_SIZE..(j + 1) * ELEMENT_SIZE], message, commitments, pubkey));
+			}
+
+			assert!(ext_ed_verify(&sig, message, pubkey));
+			assert!(!ext_ed_verify(&sig, b"this is another message", pubkey));
+			assert!(signers.iter().all(|s| ext_frost_signer_free(*s)));
+		}
+	}
+
+	#[test]
+	fn can_sign_dkg() {
+		let message = b"this is a message";
+		let participants: Vec<u32> = (1..=3).map(|i| ext_frost_dkg_new(i, 3, 2)).collect();
+		let packages: Vec<u8> = participants
+			.iter()
+			.flat_map(|p| ext_frost_dkg_package(*p))
+			.collect();
+		let results: Vec<Vec<u8>> = (1..=3)
+			.map(|i| {
+				let shares: Vec<u8> = participants
+					.iter()
+					.flat_map(|p| ext_frost_dkg_share(*p, i))
+					.collect();
+
+				ext_frost_dkg_finish(participants[i as usize - 1], &packages, &shares)
+			})
+			.collect();
+		let pubkey = &results[0][ELEMENT_SIZE..2 * ELEMENT_SIZE];
+
+		for (i, r) in results.iter().enumerate() {
+			assert_eq!(r[ELEMENT_SIZE..], results[0][ELEMENT_SIZE..]);
+			assert!(ext_frost_vss_verify(i as u16 + 1, &r[..ELEMENT_SIZE], &r[ELEMENT_SIZE..]));
+		}
+
+		let signers = [
+			ext_frost_signer_new(1, &results[0][..ELEMENT_SIZE], pubkey),
+			ext_frost_signer_new(3, &results[2][..ELEMENT_SIZE], pubkey),
+		];
+		let (sig, _) = sign_with(&signers, message, pubkey);
+
+		assert!(ext_ed_verify(&sig, message, pubkey));
+		assert!(participants.iter().all(|p| ext_frost_dkg_free(*p)));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid shares provided.")]
+	fn can_reject_dkg_share() {
+		let participants: Vec<u32> = (1..=3).map(|i| ext_frost_dkg_new(i, 3, 2)).collect();
+		let packages: Vec<u8> = participants
+			.iter()
+			.flat_map(|p| ext_frost_dkg_package(*p))
+			.collect();
+		// the share of participant 2 for participant 3 sent to participant 1
+		let shares = [ext_frost_dkg_share(participants[0], 1), ext_frost_dkg_share(participants[1], 3), ext_frost_dkg_share(participants[2], 1)].concat();
+
+		ext_frost_dkg_finish(participants[0], &packages, &shares);
+	}
+
+	#[test]
+	fn can_reject_share_below_threshold() {
+		let message = b"this is a message";
+		let res = ext_frost_dealer_keygen(&[], 3, 3);
+		let (commitment, shares) = res.split_at(3 * ELEMENT_SIZE);
+		let pubkey = &commitment[..ELEMENT_SIZE];
+		let signers = [
+			ext_frost_signer_new(1, &shares[..ELEMENT_SIZE], pubkey),
+			ext_frost_signer_new(2, &shares[ELEMENT_SIZE..2 * ELEMENT_SIZE], pubkey),
+		];
+		let (sig, _) = sign_with(&signers, message, pubkey);
+
+		assert!(!ext_ed_verify(&sig, message, pubkey));
+	}
+
+	#[test]
+	#[should_panic(expected = "No unused nonce available.")]
+	fn can_reject_nonce_reuse() {
+		let res = ext_frost_dealer_keygen(&[], 2, 2);
+		let (commitment, shares) = res.split_at(2 * ELEMENT_SIZE);
+		let signers = [
+			ext_frost_signer_new(1, &shares[..ELEMENT_SIZE], &commitment[..ELEMENT_SIZE]),
+			ext_frost_signer_new(2, &shares[ELEMENT_SIZE..], &commitment[..ELEMENT_SIZE]),
+		];
+		let commitments: Vec<u8> = signers
+			.iter()
+			.flat_map(|s| ext_frost_signer_commit(*s))
+			.collect();
+
+		ext_frost_signer_sign(signers[0], b"message", &commitments);
+		ext_frost_signer_sign(signers[0], b"message", &commitments);
+	}
+}
@@ -0,0 +1,104 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{cell::RefCell, collections::BTreeMap, thread::LocalKey};
+
+/// A slab of WASM-side objects, each addressed from JS via a u32 handle
+///
+/// Handles are never 0, so 0 can be used on the JS side as "no object". A
+/// handle is only re-used once the counter wraps and the slot has been freed.
+pub struct Handles<T> {
+	next: u32,
+	items: BTreeMap<u32, T>,
+}
+
+impl<T> Handles<T> {
+	pub fn new() -> Handles<T> {
+		Handles { next: 0, items: BTreeMap::new() }
+	}
+}
+
+impl<T> Default for Handles<T> {
+	fn default() -> Handles<T> {
+		Handles::new()
+	}
+}
+
+/// The thread-local storage for a specific type of handle
+pub type HandleKey<T> = LocalKey<RefCell<Handles<T>>>;
+
+/// Store the object, returning the handle that refers to it
+pub fn insert<T>(key: &'static HandleKey<T>, item: T) -> u32 {
+	key.with(|h| {
+		let mut h = h.borrow_mut();
+
+		loop {
+			h.next = h.next.wrapping_add(1);
+
+			if h.next != 0 && !h.items.contains_key(&h.next) {
+				break;
+			}
+		}
+
+		let handle = h.next;
+
+		h.items.insert(handle, item);
+
+		handle
+	})
+}
+
+/// Apply the function to the object behind the handle
+///
+/// Panics when the handle does not refer to a live object
+pub fn with<T, R>(key: &'static HandleKey<T>, handle: u32, f: impl FnOnce(&mut T) -> R) -> R {
+	key.with(|h| match h.borrow_mut().items.get_mut(&handle) {
+		Some(item) => f(item),
+		_ => panic!("Invalid handle provided.")
+	})
+}
+
+/// Remove the object behind the handle, returning it when available
+pub fn remove<T>(key: &'static HandleKey<T>, handle: u32) -> Option<T> {
+	key.with(|h| h.borrow_mut().items.remove(&handle))
+}
+
+#[cfg(test)]
+pub mod tests {
+	use super::*;
+
+	thread_local! {
+		static ITEMS: RefCell<Handles<u8>> = RefCell::new(Handles::new());
+	}
+
+	#[test]
+	fn can_insert_and_remove() {
+		let a = insert(&ITEMS, 1);
+		let b = insert(&ITEMS, 2);
+
+		assert!(a != 0 && b != 0 && a != b);
+		assert_eq!(with(&ITEMS, b, |i| *i), 2);
+		assert_eq!(remove(&ITEMS, a), Some(1));
+		assert_eq!(remove(&ITEMS, a), None);
+	}
+
+	#[test]
+	fn skips_zero_and_live_handles_on_wrap() {
+		let a = insert(&ITEMS, 1);
+
+		ITEMS.with(|h| h.borrow_mut().next = u32::MAX - 1);
+
+		let b = insert(&ITEMS, 2);
+		let c = insert(&ITEMS, 3);
+
+		assert_eq!(b, u32::MAX);
+		assert_eq!(a, 1);
+		assert_eq!(c, 2);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid handle provided.")]
+	fn fails_on_unknown_handle() {
+		with(&ITEMS, 0, |_| ());
+	}
+}
@@ -1,15 +1,97 @@
 // Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use blake2_rfc::blake2b::blake2b;
+use argon2::{Algorithm, Argon2, Block, Params, Version};
+use blake2_rfc::blake2b::{blake2b, Blake2b};
+use blake2_rfc::blake2s::{blake2s, Blake2s};
 use byteorder::{ByteOrder, LittleEndian};
+use hkdf::Hkdf;
 use hmac::{Hmac, Mac};
 use pbkdf2::pbkdf2;
+use ripemd160::Ripemd160;
 use scrypt::{ScryptParams, scrypt};
 use sha2::{Digest, Sha256, Sha512};
-use tiny_keccak::{Hasher, Keccak};
+use std::cell::RefCell;
+use tiny_keccak::{CShake, Hasher, Keccak, Kmac, Sha3, Shake};
 use twox_hash::XxHash;
 use wasm_bindgen::prelude::*;
+use xxhash_rust::xxh3::{xxh3_128_with_seed, xxh3_64_with_seed, Xxh3};
+
+use crate::handles::{self, Handles};
+
+/// Maximum argon2 memory cost in KiB, bound by the 4GiB wasm32 address space
+pub const ARGON2_MAX_MEMORY: u32 = 4 * 1024 * 1024;
+
+/// argon2 kdf with the specified variant, returning an empty result on invalid params
+fn argon2_hash(algorithm: Algorithm, password: &[u8], salt: &[u8], memory: u32, iterations: u32, parallelism: u32, size: u32) -> Vec<u8> {
+	// the blocks & output share the same 4GiB address space
+	if memory > ARGON2_MAX_MEMORY || (memory as u64) * 1024 + (size as u64) > (ARGON2_MAX_MEMORY as u64) * 1024 {
+		return Vec::new();
+	}
+
+	let params = match Params::new(memory, iterations, parallelism, Some(size as usize)) {
+		Ok(p) => p,
+		_ => return Vec::new()
+	};
+	let count = params.block_count();
+	// wee_alloc fails page-sized allocations with the 64-byte Block alignment, so we
+	// allocate words (with room to align) and use the aligned Block-sized middle
+	let mut words = vec![0u64; count * (Block::SIZE / 8) + 7];
+	// Block is a plain [u64; 128], so any u64 values are valid blocks
+	let (_, blocks, _) = unsafe { words.align_to_mut::<Block>() };
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	let mut res = vec![0u8; size as usize];
+
+	match Argon2::new(algorithm, Version::V0x13, params).hash_password_into_with_memory(password, salt, &mut res, &mut blocks[..count]) {
+		Ok(_) => res,
+		_ => Vec::new()
+	}
+}
+
+/// argon2d kdf from input, salt and config
+///
+/// * password: Password to hash
+/// * salt: Salt for this hash (at least 8 bytes)
+/// * memory: Memory cost in KiB (up to ARGON2_MAX_MEMORY)
+/// * iterations: Number of passes over the memory
+/// * parallelism: Number of lanes
+/// * size: Size in bytes of the resulting output (at least 4)
+///
+/// Returns a vector with the hashed result, empty when the params are invalid
+#[wasm_bindgen]
+pub fn ext_argon2d(password: &[u8], salt: &[u8], memory: u32, iterations: u32, parallelism: u32, size: u32) -> Vec<u8> {
+	argon2_hash(Algorithm::Argon2d, password, salt, memory, iterations, parallelism, size)
+}
+
+/// argon2i kdf from input, salt and config
+///
+/// * password: Password to hash
+/// * salt: Salt for this hash (at least 8 bytes)
+/// * memory: Memory cost in KiB (up to ARGON2_MAX_MEMORY)
+/// * iterations: Number of passes over the memory
+/// * parallelism: Number of lanes
+/// * size: Size in bytes of the resulting output (at least 4)
+///
+/// Returns a vector with the hashed result, empty when the params are invalid
+#[wasm_bindgen]
+pub fn ext_argon2i(password: &[u8], salt: &[u8], memory: u32, iterations: u32, parallelism: u32, size: u32) -> Vec<u8> {
+	argon2_hash(Algorithm::Argon2i, password, salt, memory, iterations, parallelism, size)
+}
+
+/// argon2id kdf from input, salt and config
+///
+/// * password: Password to hash
+/// * salt: Salt for this hash (at least 8 bytes)
+/// * memory: Memory cost in KiB (up to ARGON2_MAX_MEMORY)
+/// * iterations: Number of passes over the memory
+/// * parallelism: Number of lanes
+/// * size: Size in bytes of the resulting output (at least 4)
+///
+/// Returns a vector with the hashed result, empty when the params are invalid
+#[wasm_bindgen]
+pub fn ext_argon2id(password: &[u8], salt: &[u8], memory: u32, iterations: u32, parallelism: u32, size: u32) -> Vec<u8> {
+	argon2_hash(Algorithm::Argon2id, password, salt, memory, iterations, parallelism, size)
+}
 
 /// blake2b hash for the specified input
 ///
@@ -26,6 +108,325 @@ pub fn ext_blake2b(data: &[u8], key: &[u8], size: u32) -> Vec<u8> {
 		.to_vec()
 }
 
+/// Create a blake2b state from the full parameter block, with key, salt & personalization
+///
+/// The salt and personalization (up to 16 bytes each) are zero-padded, as per the reference
+fn blake2b_state(key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> Blake2b {
+	if size == 0 || size > 64 {
+		panic!("Invalid size provided.");
+	} else if key.len() > 64 {
+		panic!("Invalid key provided.");
+	} else if salt.len() > 16 {
+		panic!("Invalid salt provided.");
+	} else if personal.len() > 16 {
+		panic!("Invalid personal provided.");
+	}
+
+	let mut params = [0u64; 8];
+	let mut buf = [0u8; 16];
+
+	// digest length, key length, fanout = 1, depth = 1
+	params[0] = 0x0101_0000 ^ ((key.len() as u64) << 8) ^ (size as u64);
+
+	buf[..salt.len()].copy_from_slice(salt);
+	params[4] = LittleEndian::read_u64(&buf[..8]);
+	params[5] = LittleEndian::read_u64(&buf[8..]);
+
+	buf = [0u8; 16];
+	buf[..personal.len()].copy_from_slice(personal);
+	params[6] = LittleEndian::read_u64(&buf[..8]);
+	params[7] = LittleEndian::read_u64(&buf[8..]);
+
+	let mut state = Blake2b::with_parameter_block(&params);
+
+	// the key is processed as a first, zero-padded block (as per Blake2b::with_key)
+	if !key.is_empty() {
+		let mut block = [0u8; 128];
+
+		block[..key.len()].copy_from_slice(key);
+		state.update(&block);
+	}
+
+	state
+}
+
+/// Create a blake2s state from the full parameter block, with key, salt & personalization
+///
+/// The salt and personalization (up to 8 bytes each) are zero-padded, as per the reference
+fn blake2s_state(key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> Blake2s {
+	if size == 0 || size > 32 {
+		panic!("Invalid size provided.");
+	} else if key.len() > 32 {
+		panic!("Invalid key provided.");
+	} else if salt.len() > 8 {
+		panic!("Invalid salt provided.");
+	} else if personal.len() > 8 {
+		panic!("Invalid personal provided.");
+	}
+
+	let mut params = [0u32; 8];
+	let mut buf = [0u8; 8];
+
+	// digest length, key length, fanout = 1, depth = 1
+	params[0] = 0x0101_0000 ^ ((key.len() as u32) << 8) ^ size;
+
+	buf[..salt.len()].copy_from_slice(salt);
+	params[4] = LittleEndian::read_u32(&buf[..4]);
+	params[5] = LittleEndian::read_u32(&buf[4..]);
+
+	buf = [0u8; 8];
+	buf[..personal.len()].copy_from_slice(personal);
+	params[6] = LittleEndian::read_u32(&buf[..4]);
+	params[7] = LittleEndian::read_u32(&buf[4..]);
+
+	let mut state = Blake2s::with_parameter_block(&params);
+
+	// the key is processed as a first, zero-padded block (as per Blake2s::with_key)
+	if !key.is_empty() {
+		let mut block = [0u8; 64];
+
+		block[..key.len()].copy_from_slice(key);
+		state.update(&block);
+	}
+
+	state
+}
+
+/// blake2b hash for the specified input, with salt & personalization
+///
+/// * data: Arbitrary data to be hashed
+/// * key: Key to add to the hashing (normally empty), up to 64 bytes
+/// * salt: Salt for this hash, up to 16 bytes (zero-padded)
+/// * personal: Personalization for this hash, up to 16 bytes (zero-padded)
+/// * size: Size in bytes of the resulting output, 1 to 64
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_blake2b_params(data: &[u8], key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> Vec<u8> {
+	let mut state = blake2b_state(key, salt, personal, size);
+
+	state.update(data);
+
+	state
+		.finalize()
+		.as_bytes()
+		.to_vec()
+}
+
+/// blake2s hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+/// * key: Key to add to the hashing (normally empty, a MAC when provided), up to 32 bytes
+/// * size: Size in bytes of the resulting output, 1 to 32
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_blake2s(data: &[u8], key: &[u8], size: u32) -> Vec<u8> {
+	if size == 0 || size > 32 {
+		panic!("Invalid size provided.");
+	} else if key.len() > 32 {
+		panic!("Invalid key provided.");
+	}
+
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	blake2s(size as usize, key, data)
+		.as_bytes()
+		.to_vec()
+}
+
+/// blake2s hash for the specified input, with salt & personalization
+///
+/// * data: Arbitrary data to be hashed
+/// * key: Key to add to the hashing (normally empty), up to 32 bytes
+/// * salt: Salt for this hash, up to 8 bytes (zero-padded)
+/// * personal: Personalization for this hash, up to 8 bytes (zero-padded)
+/// * size: Size in bytes of the resulting output, 1 to 32
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_blake2s_params(data: &[u8], key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> Vec<u8> {
+	let mut state = blake2s_state(key, salt, personal, size);
+
+	state.update(data);
+
+	state
+		.finalize()
+		.as_bytes()
+		.to_vec()
+}
+
+/// Create a blake3 state, keyed when a 32-byte key is provided
+fn blake3_state(key: &[u8]) -> blake3::Hasher {
+	match key.len() {
+		0 => blake3::Hasher::new(),
+		32 => {
+			let mut k = [0u8; 32];
+
+			k.copy_from_slice(key);
+
+			blake3::Hasher::new_keyed(&k)
+		},
+		_ => panic!("Invalid key provided.")
+	}
+}
+
+/// Returns the blake3 extendable output of the specified size
+fn blake3_output(state: &blake3::Hasher, size: u32) -> Vec<u8> {
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	let mut res = vec![0u8; size as usize];
+
+	state
+		.finalize_xof()
+		.fill(&mut res);
+
+	res
+}
+
+/// blake3 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+/// * key: Key for the keyed hash (normally empty), 32 bytes when provided
+/// * size: Size in bytes of the resulting (extendable) output, 32 for the standard hash
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_blake3(data: &[u8], key: &[u8], size: u32) -> Vec<u8> {
+	let mut state = blake3_state(key);
+
+	state.update(data);
+
+	blake3_output(&state, size)
+}
+
+/// blake3 derive_key for the specified context and key material
+///
+/// * context: Hardcoded, globally unique & application-specific context string
+/// * material: Arbitrary key material
+/// * size: Size in bytes of the resulting (extendable) output, 32 for the standard key
+///
+/// Returns a vector with the derived key
+#[wasm_bindgen]
+pub fn ext_blake3_derive_key(context: &str, material: &[u8], size: u32) -> Vec<u8> {
+	let mut state = blake3::Hasher::new_derive_key(context);
+
+	state.update(material);
+
+	blake3_output(&state, size)
+}
+
+/// hash160 for the specified input, i.e. ripemd160(sha256(data)) as per Bitcoin
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the 20-byte hash result
+#[wasm_bindgen]
+pub fn ext_hash160(data: &[u8]) -> Vec<u8> {
+	Ripemd160::digest(&Sha256::digest(data))
+		.to_vec()
+}
+
+/// hkdf (RFC 5869) extract & expand with sha256
+///
+/// * salt: Salt for the extraction, used as the hmac key (normally empty)
+/// * ikm: The input keying material
+/// * info: Context information for the expansion (normally empty)
+/// * size: Size in bytes of the resulting output, up to 255 * 32
+///
+/// Returns a vector with the output keying material
+#[wasm_bindgen]
+pub fn ext_hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8], size: u32) -> Vec<u8> {
+	let mut res = vec![0u8; size as usize];
+
+	match Hkdf::<Sha256>::new(Some(salt), ikm).expand(info, &mut res) {
+		Ok(_) => res,
+		_ => panic!("Invalid size provided.")
+	}
+}
+
+/// hkdf (RFC 5869) extract with sha256, i.e. hmac(salt, ikm)
+///
+/// * salt: Salt for the extraction, used as the hmac key (normally empty)
+/// * ikm: The input keying material
+///
+/// Returns a vector with the 32-byte pseudorandom key
+#[wasm_bindgen]
+pub fn ext_hkdf_sha256_extract(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+	Hkdf::<Sha256>::extract(Some(salt), ikm).0.to_vec()
+}
+
+/// hkdf (RFC 5869) expand with sha256
+///
+/// * prk: The pseudorandom key, at least 32 bytes
+/// * info: Context information for the expansion (normally empty)
+/// * size: Size in bytes of the resulting output, up to 255 * 32
+///
+/// Returns a vector with the output keying material
+#[wasm_bindgen]
+pub fn ext_hkdf_sha256_expand(prk: &[u8], info: &[u8], size: u32) -> Vec<u8> {
+	match Hkdf::<Sha256>::from_prk(prk) {
+		Ok(h) => {
+			let mut res = vec![0u8; size as usize];
+
+			match h.expand(info, &mut res) {
+				Ok(_) => res,
+				_ => panic!("Invalid size provided.")
+			}
+		},
+		_ => panic!("Invalid prk provided.")
+	}
+}
+
+/// hkdf (RFC 5869) extract & expand with sha512
+///
+/// * salt: Salt for the extraction, used as the hmac key (normally empty)
+/// * ikm: The input keying material
+/// * info: Context information for the expansion (normally empty)
+/// * size: Size in bytes of the resulting output, up to 255 * 64
+///
+/// Returns a vector with the output keying material
+#[wasm_bindgen]
+pub fn ext_hkdf_sha512(salt: &[u8], ikm: &[u8], info: &[u8], size: u32) -> Vec<u8> {
+	let mut res = vec![0u8; size as usize];
+
+	match Hkdf::<Sha512>::new(Some(salt), ikm).expand(info, &mut res) {
+		Ok(_) => res,
+		_ => panic!("Invalid size provided.")
+	}
+}
+
+/// hkdf (RFC 5869) extract with sha512, i.e. hmac(salt, ikm)
+///
+/// * salt: Salt for the extraction, used as the hmac key (normally empty)
+/// * ikm: The input keying material
+///
+/// Returns a vector with the 64-byte pseudorandom key
+#[wasm_bindgen]
+pub fn ext_hkdf_sha512_extract(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+	Hkdf::<Sha512>::extract(Some(salt), ikm).0.to_vec()
+}
+
+/// hkdf (RFC 5869) expand with sha512
+///
+/// * prk: The pseudorandom key, at least 64 bytes
+/// * info: Context information for the expansion (normally empty)
+/// * size: Size in bytes of the resulting output, up to 255 * 64
+///
+/// Returns a vector with the output keying material
+#[wasm_bindgen]
+pub fn ext_hkdf_sha512_expand(prk: &[u8], info: &[u8], size: u32) -> Vec<u8> {
+	match Hkdf::<Sha512>::from_prk(prk) {
+		Ok(h) => {
+			let mut res = vec![0u8; size as usize];
+
+			match h.expand(info, &mut res) {
+				Ok(_) => res,
+				_ => panic!("Invalid size provided.")
+			}
+		},
+		_ => panic!("Invalid prk provided.")
+	}
+}
+
 /// hmac with sha256
 #[wasm_bindgen]
 pub fn ext_hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
@@ -91,102 +492,996 @@ pub fn ext_keccak512(data: &[u8]) -> Vec<u8> {
 	res.to_vec()
 }
 
-/// pbkdf2 kdf from an input, salt for the number of specified rounds
+/// Run the data through a Keccak-based hasher, returning the output of the specified size
+fn keccak_output<H: Hasher>(mut hasher: H, data: &[u8], size: u32) -> Vec<u8> {
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	let mut res = vec![0u8; size as usize];
+
+	hasher.update(data);
+	hasher.finalize(&mut res);
+
+	res
+}
+
+/// Create a FIPS 202 sha3-224 hash for the specified input
 ///
 /// * data: Arbitrary data to be hashed
-/// * salt: Salt for this hash
-/// * rounds: The number of rounds to perform
 ///
-/// Returns a vector with the hashed result
+/// Returns the hash as a vector
 #[wasm_bindgen]
-pub fn ext_pbkdf2(data: &[u8], salt: &[u8], rounds: u32) -> Vec<u8> {
-	let mut res = [0u8; 64];
+pub fn ext_sha3_224(data: &[u8]) -> Vec<u8> {
+	keccak_output(Sha3::v224(), data, 28)
+}
 
-	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
-	pbkdf2::<Hmac::<Sha512>>(data, salt, rounds as usize, &mut res);
+/// Create a FIPS 202 sha3-256 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns the hash as a vector
+#[wasm_bindgen]
+pub fn ext_sha3_256(data: &[u8]) -> Vec<u8> {
+	keccak_output(Sha3::v256(), data, 32)
+}
 
-	res.to_vec()
+/// Create a FIPS 202 sha3-384 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns the hash as a vector
+#[wasm_bindgen]
+pub fn ext_sha3_384(data: &[u8]) -> Vec<u8> {
+	keccak_output(Sha3::v384(), data, 48)
 }
 
-/// scrypt kdf from input, salt and config
+/// Create a FIPS 202 sha3-512 hash for the specified input
 ///
-/// * password: Password to hash
-/// * salt: Salt for this hash
-/// * log2_n: log2(n)
-/// * r: r
-/// * p: p
+/// * data: Arbitrary data to be hashed
 ///
-/// Returns vector with the hashed result
+/// Returns the hash as a vector
 #[wasm_bindgen]
-pub fn ext_scrypt(password: &[u8], salt: &[u8], log2_n: u8, r: u32, p: u32) -> Vec<u8> {
-	match ScryptParams::new(log2_n, r, p) {
-		Ok(p) => {
-			let mut res = [0u8; 64];
+pub fn ext_sha3_512(data: &[u8]) -> Vec<u8> {
+	keccak_output(Sha3::v512(), data, 64)
+}
 
-			match scrypt(password, salt, &p, &mut res) {
-				Ok(_) => res.to_vec(),
-				_ => panic!("Invalid scrypt hash.")
-			}
-		},
-		_ => panic!("Invalid scrypt params.")
-	}
+/// Create a FIPS 202 shake128 extendable output for the specified input
+///
+/// * data: Arbitrary data to be hashed
+/// * size: Size in bytes of the resulting output
+///
+/// Returns the output as a vector
+#[wasm_bindgen]
+pub fn ext_shake128(data: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(Shake::v128(), data, size)
+}
+
+/// Create a FIPS 202 shake256 extendable output for the specified input
+///
+/// * data: Arbitrary data to be hashed
+/// * size: Size in bytes of the resulting output
+///
+/// Returns the output as a vector
+#[wasm_bindgen]
+pub fn ext_shake256(data: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(Shake::v256(), data, size)
+}
 
+/// Create a SP 800-185 cshake128 output for the specified input
+///
+/// * data: Arbitrary data to be hashed
+/// * name: Function name (normally empty, reserved for NIST-defined functions)
+/// * custom: Customization string for domain separation
+/// * size: Size in bytes of the resulting output
+///
+/// Returns the output as a vector
+#[wasm_bindgen]
+pub fn ext_cshake128(data: &[u8], name: &[u8], custom: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(CShake::v128(name, custom), data, size)
 }
 
-/// sha256 hash for the specified input
+/// Create a SP 800-185 cshake256 output for the specified input
 ///
 /// * data: Arbitrary data to be hashed
+/// * name: Function name (normally empty, reserved for NIST-defined functions)
+/// * custom: Customization string for domain separation
+/// * size: Size in bytes of the resulting output
 ///
-/// Returns a vector with the hash result
+/// Returns the output as a vector
 #[wasm_bindgen]
-pub fn ext_sha256(data: &[u8]) -> Vec<u8> {
-	let mut hasher = Sha256::new();
+pub fn ext_cshake256(data: &[u8], name: &[u8], custom: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(CShake::v256(name, custom), data, size)
+}
 
-	hasher.input(data);
+/// Create a SP 800-185 kmac128 for the specified input
+///
+/// * key: Arbitrary length key
+/// * data: Arbitrary data to be authenticated
+/// * custom: Customization string for domain separation (normally empty)
+/// * size: Size in bytes of the resulting output, which is bound into the mac
+///
+/// Returns the mac as a vector
+#[wasm_bindgen]
+pub fn ext_kmac128(key: &[u8], data: &[u8], custom: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(Kmac::v128(key, custom), data, size)
+}
 
-	hasher
-		.result()
-		.to_vec()
+/// Create a SP 800-185 kmac256 for the specified input
+///
+/// * key: Arbitrary length key
+/// * data: Arbitrary data to be authenticated
+/// * custom: Customization string for domain separation (normally empty)
+/// * size: Size in bytes of the resulting output, which is bound into the mac
+///
+/// Returns the mac as a vector
+#[wasm_bindgen]
+pub fn ext_kmac256(key: &[u8], data: &[u8], custom: &[u8], size: u32) -> Vec<u8> {
+	keccak_output(Kmac::v256(key, custom), data, size)
+}
+
+/// pbkdf2 kdf from an input, salt for the number of specified rounds
+///
+/// * data: Arbitrary data to be hashed
+/// * salt: Salt for this hash
+/// * rounds: The number of rounds to perform
+///
+/// Returns a vector with the hashed result
+#[wasm_bindgen]
+pub fn ext_pbkdf2(data: &[u8], salt: &[u8], rounds: u32) -> Vec<u8> {
+	ext_pbkdf2_sha512(data, salt, rounds, 64)
+}
+
+/// pbkdf2-hmac-sha256 kdf from an input, salt for the number of specified rounds
+///
+/// * data: Arbitrary data to be hashed
+/// * salt: Salt for this hash
+/// * rounds: The number of rounds to perform
+/// * size: Size in bytes of the resulting output
+///
+/// Returns a vector with the hashed result
+#[wasm_bindgen]
+pub fn ext_pbkdf2_sha256(data: &[u8], salt: &[u8], rounds: u32, size: u32) -> Vec<u8> {
+	let mut res = vec![0u8; size as usize];
+
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	pbkdf2::<Hmac::<Sha256>>(data, salt, rounds as usize, &mut res);
+
+	res
+}
+
+/// pbkdf2-hmac-sha512 kdf from an input, salt for the number of specified rounds
+///
+/// * data: Arbitrary data to be hashed
+/// * salt: Salt for this hash
+/// * rounds: The number of rounds to perform
+/// * size: Size in bytes of the resulting output
+///
+/// Returns a vector with the hashed result
+#[wasm_bindgen]
+pub fn ext_pbkdf2_sha512(data: &[u8], salt: &[u8], rounds: u32, size: u32) -> Vec<u8> {
+	let mut res = vec![0u8; size as usize];
+
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	pbkdf2::<Hmac::<Sha512>>(data, salt, rounds as usize, &mut res);
+
+	res
+}
+
+/// ripemd160 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the 20-byte hash result
+#[wasm_bindgen]
+pub fn ext_ripemd160(data: &[u8]) -> Vec<u8> {
+	Ripemd160::digest(data)
+		.to_vec()
+}
+
+/// scrypt kdf from input, salt and config
+///
+/// * password: Password to hash
+/// * salt: Salt for this hash
+/// * log2_n: log2(n)
+/// * r: r
+/// * p: p
+///
+/// Returns vector with the hashed result
+#[wasm_bindgen]
+pub fn ext_scrypt(password: &[u8], salt: &[u8], log2_n: u8, r: u32, p: u32) -> Vec<u8> {
+	ext_scrypt_size(password, salt, log2_n, r, p, 64)
+}
+
+/// scrypt kdf from input, salt and config, with the specified output size
+///
+/// * password: Password to hash
+/// * salt: Salt for this hash
+/// * log2_n: log2(n)
+/// * r: r
+/// * p: p
+/// * size: Size in bytes of the resulting output (dklen)
+///
+/// Returns vector with the hashed result
+#[wasm_bindgen]
+pub fn ext_scrypt_size(password: &[u8], salt: &[u8], log2_n: u8, r: u32, p: u32, size: u32) -> Vec<u8> {
+	match ScryptParams::new(log2_n, r, p) {
+		Ok(p) => {
+			let mut res = vec![0u8; size as usize];
+
+			match scrypt(password, salt, &p, &mut res) {
+				Ok(_) => res,
+				_ => panic!("Invalid scrypt hash.")
+			}
+		},
+		_ => panic!("Invalid scrypt params.")
+	}
+}
+
+/// sha256 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_sha256(data: &[u8]) -> Vec<u8> {
+	let mut hasher = Sha256::new();
+
+	hasher.input(data);
+
+	hasher
+		.result()
+		.to_vec()
+}
+
+/// double sha256 hash for the specified input, i.e. sha256(sha256(data)) as per Bitcoin
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_sha256d(data: &[u8]) -> Vec<u8> {
+	Sha256::digest(&Sha256::digest(data))
+		.to_vec()
+}
+
+/// sha512 hash for the specified input
+///
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_sha512(data: &[u8]) -> Vec<u8> {
+	let mut hasher = Sha512::new();
+
+	hasher.input(data);
+
+	hasher
+		.result()
+		.to_vec()
+}
+
+/// Create a sha256 state with the BIP340 tag prefix, sha256(tag) || sha256(tag)
+fn tagged_state(tag: &[u8]) -> Sha256 {
+	let tag_hash = Sha256::digest(tag);
+	let mut hasher = Sha256::new();
+
+	hasher.input(tag_hash);
+	hasher.input(tag_hash);
+
+	hasher
+}
+
+/// BIP340 tagged hash, sha256(sha256(tag) || sha256(tag) || data)
+pub(crate) fn tagged_hash(tag: &[u8], data: &[&[u8]]) -> [u8; 32] {
+	let mut hasher = tagged_state(tag);
+	let mut res = [0u8; 32];
+
+	for d in data {
+		hasher.input(d);
+	}
+
+	res.copy_from_slice(&hasher.result());
+
+	res
+}
+
+/// BIP340 tagged hash for the specified input
+///
+/// * tag: The tag, e.g. "BIP0340/challenge" or "TapLeaf"
+/// * data: Arbitrary data to be hashed
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_tagged_hash(tag: &str, data: &[u8]) -> Vec<u8> {
+	tagged_hash(tag.as_bytes(), &[data]).to_vec()
+}
+
+/// twox hash for the specified input and rounds
+///
+/// * data: Arbitrary data to be hashed
+/// * rounds: Number of 8-byte rounds to add to the output
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_twox(data: &[u8], rounds: u32) -> Vec<u8> {
+	use ::std::hash::Hasher;
+	let mut res = vec![];
+	let mut buf = [0u8; 8];
+
+	for round in 0..rounds {
+		// we cast to u64 here - due to the WASM, we'd rather have u32 inputs
+		let mut hasher = XxHash::with_seed(round as u64);
+
+		hasher.write(data);
+		LittleEndian::write_u64(&mut buf, hasher.finish());
+		res.extend_from_slice(&buf);
+	}
+
+	res
+}
+
+/// Decode the 64-bit xxh seed from the 8 little-endian bytes
+fn xxh_seed(seed: &[u8]) -> u64 {
+	match seed.len() {
+		8 => LittleEndian::read_u64(seed),
+		_ => panic!("Invalid seed provided.")
+	}
+}
+
+/// xxh3 64-bit hash for the specified input and seed
+///
+/// * data: Arbitrary data to be hashed
+/// * seed: UIntArray with 8 element, the 64-bit seed (normally 0) as little-endian
+///
+/// Returns a vector with the 8-byte hash result, in canonical (big-endian) form
+#[wasm_bindgen]
+pub fn ext_xxh3_64(data: &[u8], seed: &[u8]) -> Vec<u8> {
+	xxh3_64_with_seed(data, xxh_seed(seed))
+		.to_be_bytes()
+		.to_vec()
+}
+
+/// xxh3 128-bit hash for the specified input and seed
+///
+/// * data: Arbitrary data to be hashed
+/// * seed: UIntArray with 8 element, the 64-bit seed (normally 0) as little-endian
+///
+/// Returns a vector with the 16-byte hash result, in canonical (big-endian) form
+#[wasm_bindgen]
+pub fn ext_xxh3_128(data: &[u8], seed: &[u8]) -> Vec<u8> {
+	xxh3_128_with_seed(data, xxh_seed(seed))
+		.to_be_bytes()
+		.to_vec()
+}
+
+/// xxh64 hash for the specified input and seed
+///
+/// Unlike ext_twox (little-endian, round index as seed), this is plain xxh64
+///
+/// * data: Arbitrary data to be hashed
+/// * seed: UIntArray with 8 element, the 64-bit seed (normally 0) as little-endian
+///
+/// Returns a vector with the 8-byte hash result, in canonical (big-endian) form
+#[wasm_bindgen]
+pub fn ext_xxh64(data: &[u8], seed: &[u8]) -> Vec<u8> {
+	use ::std::hash::Hasher;
+	let mut hasher = XxHash::with_seed(xxh_seed(seed));
+
+	hasher.write(data);
+
+	hasher
+		.finish()
+		.to_be_bytes()
+		.to_vec()
+}
+
+/// A streaming hasher, kept WASM-side behind a handle
+trait StreamHasher {
+	/// Add a chunk of data to the hash
+	fn update(&mut self, data: &[u8]);
+
+	/// Returns a vector with the hash result, consuming the hasher
+	fn finalize(self: Box<Self>) -> Vec<u8>;
+}
+
+thread_local! {
+	static HASHERS: RefCell<Handles<Box<dyn StreamHasher>>> = RefCell::new(Handles::new());
+}
+
+/// Store the hasher, returning the handle that refers to it
+fn insert_hasher(hasher: impl StreamHasher + 'static) -> u32 {
+	handles::insert(&HASHERS, Box::new(hasher))
+}
+
+/// Add a chunk of data to the hash
+///
+/// * hasher: the hasher handle, from any of the ext_*_hasher_new functions
+/// * data: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_hasher_update(hasher: u32, data: &[u8]) {
+	handles::with(&HASHERS, hasher, |h| h.update(data))
+}
+
+/// Finalize the hash, releasing the hasher
+///
+/// * hasher: the hasher handle, from any of the ext_*_hasher_new functions
+///
+/// Returns a vector with the hash result
+#[wasm_bindgen]
+pub fn ext_hasher_finalize(hasher: u32) -> Vec<u8> {
+	match handles::remove(&HASHERS, hasher) {
+		Some(h) => h.finalize(),
+		_ => panic!("Invalid handle provided.")
+	}
+}
+
+/// Release the hasher without finalizing
+///
+/// * hasher: the hasher handle, from any of the ext_*_hasher_new functions
+///
+/// returned true if the handle referred to a live hasher
+#[wasm_bindgen]
+pub fn ext_hasher_free(hasher: u32) -> bool {
+	handles::remove(&HASHERS, hasher).is_some()
+}
+
+/// Streaming blake2b hasher, matching ext_blake2b for the concatenated input
+struct Blake2bHasher {
+	state: Blake2b,
+}
+
+impl StreamHasher for Blake2bHasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.finalize()
+			.as_bytes()
+			.to_vec()
+	}
+}
+
+/// Create a streaming blake2b hasher, matching ext_blake2b for the concatenated input
+///
+/// * key: Key to add to the hashing (normally empty), up to 64 bytes
+/// * size: Size in bytes of the resulting output, 1 to 64
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake2b_hasher_new(key: &[u8], size: u32) -> u32 {
+	if size == 0 || size > 64 {
+		panic!("Invalid size provided.");
+	} else if key.len() > 64 {
+		panic!("Invalid key provided.");
+	}
+
+	// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+	insert_hasher(Blake2bHasher { state: Blake2b::with_key(size as usize, key) })
+}
+
+/// Create a streaming blake2b hasher with salt & personalization, matching ext_blake2b_params
+///
+/// * key: Key to add to the hashing (normally empty), up to 64 bytes
+/// * salt: Salt for this hash, up to 16 bytes (zero-padded)
+/// * personal: Personalization for this hash, up to 16 bytes (zero-padded)
+/// * size: Size in bytes of the resulting output, 1 to 64
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake2b_params_hasher_new(key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> u32 {
+	insert_hasher(Blake2bHasher { state: blake2b_state(key, salt, personal, size) })
+}
+
+/// Streaming blake2s hasher, matching ext_blake2s for the concatenated input
+struct Blake2sHasher {
+	state: Blake2s,
+}
+
+impl StreamHasher for Blake2sHasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.finalize()
+			.as_bytes()
+			.to_vec()
+	}
+}
+
+/// Create a streaming blake2s hasher, matching ext_blake2s for the concatenated input
+///
+/// * key: Key to add to the hashing (normally empty), up to 32 bytes
+/// * size: Size in bytes of the resulting output, 1 to 32
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake2s_hasher_new(key: &[u8], size: u32) -> u32 {
+	insert_hasher(Blake2sHasher { state: blake2s_state(key, &[], &[], size) })
+}
+
+/// Create a streaming blake2s hasher with salt & personalization, matching ext_blake2s_params
+///
+/// * key: Key to add to the hashing (normally empty), up to 32 bytes
+/// * salt: Salt for this hash, up to 8 bytes (zero-padded)
+/// * personal: Personalization for this hash, up to 8 bytes (zero-padded)
+/// * size: Size in bytes of the resulting output, 1 to 32
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake2s_params_hasher_new(key: &[u8], salt: &[u8], personal: &[u8], size: u32) -> u32 {
+	insert_hasher(Blake2sHasher { state: blake2s_state(key, salt, personal, size) })
+}
+
+/// Streaming blake3 hasher, matching ext_blake3 and ext_blake3_derive_key for the concatenated input
+struct Blake3Hasher {
+	size: u32,
+	state: blake3::Hasher,
+}
+
+impl StreamHasher for Blake3Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		blake3_output(&self.state, self.size)
+	}
+}
+
+/// Create a streaming blake3 hasher, matching ext_blake3 for the concatenated input
+///
+/// * key: Key for the keyed hash (normally empty), 32 bytes when provided
+/// * size: Size in bytes of the resulting (extendable) output, 32 for the standard hash
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake3_hasher_new(key: &[u8], size: u32) -> u32 {
+	insert_hasher(Blake3Hasher { size, state: blake3_state(key) })
+}
+
+/// Create a streaming blake3 hasher in derive_key mode, matching ext_blake3_derive_key
+///
+/// * context: Hardcoded, globally unique & application-specific context string
+/// * size: Size in bytes of the resulting (extendable) output
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_blake3_derive_key_hasher_new(context: &str, size: u32) -> u32 {
+	insert_hasher(Blake3Hasher { size, state: blake3::Hasher::new_derive_key(context) })
+}
+
+/// Streaming hash160 hasher, matching ext_hash160 for the concatenated input
+struct Hash160Hasher {
+	state: Sha256,
+}
+
+impl StreamHasher for Hash160Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		Ripemd160::digest(&self.state.result())
+			.to_vec()
+	}
+}
+
+/// Create a streaming hash160 hasher, matching ext_hash160 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_hash160_hasher_new() -> u32 {
+	insert_hasher(Hash160Hasher { state: Sha256::new() })
+}
+
+/// Streaming keccak256 hasher, matching ext_keccak256 for the concatenated input
+struct Keccak256Hasher {
+	state: Keccak,
+}
+
+impl StreamHasher for Keccak256Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		let mut res = [0u8; 32];
+
+		self.state.finalize(&mut res);
+
+		res.to_vec()
+	}
+}
+
+/// Create a streaming keccak256 hasher, matching ext_keccak256 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_keccak256_hasher_new() -> u32 {
+	insert_hasher(Keccak256Hasher { state: Keccak::v256() })
+}
+
+/// Streaming keccak512 hasher, matching ext_keccak512 for the concatenated input
+struct Keccak512Hasher {
+	state: Keccak,
+}
+
+impl StreamHasher for Keccak512Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		let mut res = [0u8; 64];
+
+		self.state.finalize(&mut res);
+
+		res.to_vec()
+	}
+}
+
+/// Create a streaming keccak512 hasher, matching ext_keccak512 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_keccak512_hasher_new() -> u32 {
+	insert_hasher(Keccak512Hasher { state: Keccak::v512() })
+}
+
+/// Streaming FIPS 202 & SP 800-185 hasher, matching the one-shot sha3, shake, cshake & kmac functions
+struct Sha3Hasher<H: Hasher> {
+	size: u32,
+	state: H,
+}
+
+impl<H: Hasher> StreamHasher for Sha3Hasher<H> {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+		let mut res = vec![0u8; self.size as usize];
+
+		self.state.finalize(&mut res);
+
+		res
+	}
+}
+
+/// Create a streaming sha3-224 hasher, matching ext_sha3_224 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha3_224_hasher_new() -> u32 {
+	insert_hasher(Sha3Hasher { size: 28, state: Sha3::v224() })
+}
+
+/// Create a streaming sha3-256 hasher, matching ext_sha3_256 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha3_256_hasher_new() -> u32 {
+	insert_hasher(Sha3Hasher { size: 32, state: Sha3::v256() })
+}
+
+/// Create a streaming sha3-384 hasher, matching ext_sha3_384 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha3_384_hasher_new() -> u32 {
+	insert_hasher(Sha3Hasher { size: 48, state: Sha3::v384() })
+}
+
+/// Create a streaming sha3-512 hasher, matching ext_sha3_512 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha3_512_hasher_new() -> u32 {
+	insert_hasher(Sha3Hasher { size: 64, state: Sha3::v512() })
+}
+
+/// Create a streaming shake128 hasher, matching ext_shake128 for the concatenated input
+///
+/// * size: Size in bytes of the resulting output
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_shake128_hasher_new(size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: Shake::v128() })
+}
+
+/// Create a streaming shake256 hasher, matching ext_shake256 for the concatenated input
+///
+/// * size: Size in bytes of the resulting output
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_shake256_hasher_new(size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: Shake::v256() })
+}
+
+/// Create a streaming cshake128 hasher, matching ext_cshake128 for the concatenated input
+///
+/// * name: Function name (normally empty, reserved for NIST-defined functions)
+/// * custom: Customization string for domain separation
+/// * size: Size in bytes of the resulting output
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_cshake128_hasher_new(name: &[u8], custom: &[u8], size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: CShake::v128(name, custom) })
+}
+
+/// Create a streaming cshake256 hasher, matching ext_cshake256 for the concatenated input
+///
+/// * name: Function name (normally empty, reserved for NIST-defined functions)
+/// * custom: Customization string for domain separation
+/// * size: Size in bytes of the resulting output
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_cshake256_hasher_new(name: &[u8], custom: &[u8], size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: CShake::v256(name, custom) })
+}
+
+/// Create a streaming kmac128 hasher, matching ext_kmac128 for the concatenated input
+///
+/// * key: Arbitrary length key
+/// * custom: Customization string for domain separation (normally empty)
+/// * size: Size in bytes of the resulting output, which is bound into the mac
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_kmac128_hasher_new(key: &[u8], custom: &[u8], size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: Kmac::v128(key, custom) })
+}
+
+/// Create a streaming kmac256 hasher, matching ext_kmac256 for the concatenated input
+///
+/// * key: Arbitrary length key
+/// * custom: Customization string for domain separation (normally empty)
+/// * size: Size in bytes of the resulting output, which is bound into the mac
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_kmac256_hasher_new(key: &[u8], custom: &[u8], size: u32) -> u32 {
+	insert_hasher(Sha3Hasher { size, state: Kmac::v256(key, custom) })
+}
+
+/// Streaming ripemd160 hasher, matching ext_ripemd160 for the concatenated input
+struct Ripemd160Hasher {
+	state: Ripemd160,
+}
+
+impl StreamHasher for Ripemd160Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.result()
+			.to_vec()
+	}
+}
+
+/// Create a streaming ripemd160 hasher, matching ext_ripemd160 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_ripemd160_hasher_new() -> u32 {
+	insert_hasher(Ripemd160Hasher { state: Ripemd160::new() })
+}
+
+/// Streaming sha256 hasher, matching ext_sha256 for the concatenated input
+struct Sha256Hasher {
+	state: Sha256,
+}
+
+impl StreamHasher for Sha256Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.result()
+			.to_vec()
+	}
+}
+
+/// Create a streaming sha256 hasher, matching ext_sha256 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha256_hasher_new() -> u32 {
+	insert_hasher(Sha256Hasher { state: Sha256::new() })
+}
+
+/// Streaming double sha256 hasher, matching ext_sha256d for the concatenated input
+struct Sha256dHasher {
+	state: Sha256,
+}
+
+impl StreamHasher for Sha256dHasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		Sha256::digest(&self.state.result())
+			.to_vec()
+	}
+}
+
+/// Create a streaming double sha256 hasher, matching ext_sha256d for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha256d_hasher_new() -> u32 {
+	insert_hasher(Sha256dHasher { state: Sha256::new() })
+}
+
+/// Streaming sha512 hasher, matching ext_sha512 for the concatenated input
+struct Sha512Hasher {
+	state: Sha512,
+}
+
+impl StreamHasher for Sha512Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.result()
+			.to_vec()
+	}
+}
+
+/// Create a streaming sha512 hasher, matching ext_sha512 for the concatenated input
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_sha512_hasher_new() -> u32 {
+	insert_hasher(Sha512Hasher { state: Sha512::new() })
+}
+
+/// Streaming BIP340 tagged hasher, matching ext_tagged_hash for the concatenated input
+struct TaggedHasher {
+	state: Sha256,
+}
+
+impl StreamHasher for TaggedHasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.input(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		self.state
+			.result()
+			.to_vec()
+	}
+}
+
+/// Create a streaming BIP340 tagged hasher, matching ext_tagged_hash for the concatenated input
+///
+/// * tag: The tag, e.g. "BIP0340/challenge" or "TapLeaf"
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_tagged_hasher_new(tag: &str) -> u32 {
+	insert_hasher(TaggedHasher { state: tagged_state(tag.as_bytes()) })
+}
+
+/// Streaming twox hasher, matching ext_twox for the concatenated input
+struct TwoxHasher {
+	states: Vec<XxHash>,
+}
+
+impl StreamHasher for TwoxHasher {
+	fn update(&mut self, data: &[u8]) {
+		use ::std::hash::Hasher;
+
+		for state in self.states.iter_mut() {
+			state.write(data);
+		}
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		use ::std::hash::Hasher;
+		let mut res = vec![];
+		let mut buf = [0u8; 8];
+
+		for state in self.states.iter() {
+			LittleEndian::write_u64(&mut buf, state.finish());
+			res.extend_from_slice(&buf);
+		}
+
+		res
+	}
 }
 
-/// sha512 hash for the specified input
+/// Create a streaming twox hasher, matching ext_twox for the concatenated input
 ///
-/// * data: Arbitrary data to be hashed
+/// * rounds: Number of 8-byte rounds to add to the output
 ///
-/// Returns a vector with the hash result
+/// returned value is the handle of the hasher
 #[wasm_bindgen]
-pub fn ext_sha512(data: &[u8]) -> Vec<u8> {
-	let mut hasher = Sha512::new();
+pub fn ext_twox_hasher_new(rounds: u32) -> u32 {
+	insert_hasher(TwoxHasher {
+		// we cast to u64 here - due to the WASM, we'd rather have u32 inputs
+		states: (0..rounds).map(|round| XxHash::with_seed(round as u64)).collect()
+	})
+}
 
-	hasher.input(data);
+/// Streaming xxh3 hasher, matching ext_xxh3_64 or ext_xxh3_128 for the concatenated input
+struct Xxh3Hasher {
+	size: u32,
+	state: Xxh3,
+}
 
-	hasher
-		.result()
-		.to_vec()
+impl StreamHasher for Xxh3Hasher {
+	fn update(&mut self, data: &[u8]) {
+		self.state.update(data);
+	}
+
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		match self.size {
+			8 => self.state.digest().to_be_bytes().to_vec(),
+			_ => self.state.digest128().to_be_bytes().to_vec()
+		}
+	}
 }
 
-/// twox hash for the specified input and rounds
+/// Create a streaming xxh3 hasher, matching ext_xxh3_64 or ext_xxh3_128 for the concatenated input
 ///
-/// * data: Arbitrary data to be hashed
-/// * rounds: Number of 8-byte rounds to add to the output
+/// * seed: UIntArray with 8 element, the 64-bit seed (normally 0) as little-endian
+/// * size: Size in bytes of the resulting output, 8 or 16
 ///
-/// Returns a vector with the hash result
+/// returned value is the handle of the hasher
 #[wasm_bindgen]
-pub fn ext_twox(data: &[u8], rounds: u32) -> Vec<u8> {
-	use ::std::hash::Hasher;
-	let mut res = vec![];
-	let mut buf = [0u8; 8];
+pub fn ext_xxh3_hasher_new(seed: &[u8], size: u32) -> u32 {
+	if size != 8 && size != 16 {
+		panic!("Invalid size provided.");
+	}
 
-	for round in 0..rounds {
-		// we cast to u64 here - due to the WASM, we'd rather have u32 inputs
-		let mut hasher = XxHash::with_seed(round as u64);
+	insert_hasher(Xxh3Hasher { size, state: Xxh3::with_seed(xxh_seed(seed)) })
+}
 
-		hasher.write(data);
-		LittleEndian::write_u64(&mut buf, hasher.finish());
-		res.extend_from_slice(&buf);
+/// Streaming xxh64 hasher, matching ext_xxh64 for the concatenated input
+struct Xxh64Hasher {
+	state: XxHash,
+}
+
+impl StreamHasher for Xxh64Hasher {
+	fn update(&mut self, data: &[u8]) {
+		use ::std::hash::Hasher;
+
+		self.state.write(data);
 	}
 
-	res
+	fn finalize(self: Box<Self>) -> Vec<u8> {
+		use ::std::hash::Hasher;
+
+		self.state
+			.finish()
+			.to_be_bytes()
+			.to_vec()
+	}
+}
+
+/// Create a streaming xxh64 hasher, matching ext_xxh64 for the concatenated input
+///
+/// * seed: UIntArray with 8 element, the 64-bit seed (normally 0) as little-endian
+///
+/// returned value is the handle of the hasher
+#[wasm_bindgen]
+pub fn ext_xxh64_hasher_new(seed: &[u8]) -> u32 {
+	insert_hasher(Xxh64Hasher { state: XxHash::with_seed(xxh_seed(seed)) })
 }
 
 #[cfg(test)]
@@ -194,6 +1489,52 @@ pub mod tests {
 	use hex_literal::hex;
 	use super::*;
 
+	#[test]
+	fn can_argon2() {
+		let password = b"password";
+		let salt = b"somesalt";
+		let expected_d = hex!("25c4ee8ba448054b49efc804e478b9d823be1f9bd2e99f51d6ec4007a3a1501f");
+		let expected_i = hex!("89e9029f4637b295beb027056a7336c414fadd43f6b208645281cb214a56452f");
+		let expected_id = hex!("9dfeb910e80bad0311fee20f9c0e2b12c17987b4cac90c2ef54d5b3021c68bfe");
+
+		assert_eq!(ext_argon2d(password, salt, 256, 2, 1, 32)[..], expected_d[..]);
+		assert_eq!(ext_argon2i(password, salt, 256, 2, 1, 32)[..], expected_i[..]);
+		assert_eq!(ext_argon2id(password, salt, 256, 2, 1, 32)[..], expected_id[..]);
+	}
+
+	#[test]
+	fn can_argon2_parallel() {
+		let expected = hex!("6d093c501fd5999645e0ea3bf620d7b8be7fd2db59c20d9fff9539da2bf57037");
+		let hash = ext_argon2id(b"password", b"somesalt", 256, 2, 2, 32);
+
+		assert_eq!(hash[..], expected[..]);
+		assert_eq!(ext_argon2id(b"password", b"somesalt", 256, 2, 2, 64).len(), 64);
+	}
+
+	#[test]
+	fn argon2_fails_on_invalid_params() {
+		let cases: [(&[u8], u32, u32, u32, u32); 6] = [
+			// memory above the address space
+			(b"somesalt", ARGON2_MAX_MEMORY + 1, 2, 1, 32),
+			// no iterations
+			(b"somesalt", 256, 0, 1, 32),
+			// no lanes
+			(b"somesalt", 256, 2, 0, 32),
+			// output too short
+			(b"somesalt", 256, 2, 1, 3),
+			// output above the address space
+			(b"somesalt", 256, 2, 1, u32::MAX),
+			// salt too short
+			(b"short", 256, 2, 1, 32)
+		];
+
+		for (salt, memory, iterations, parallelism, size) in cases.iter() {
+			assert!(ext_argon2d(b"password", salt, *memory, *iterations, *parallelism, *size).is_empty());
+			assert!(ext_argon2i(b"password", salt, *memory, *iterations, *parallelism, *size).is_empty());
+			assert!(ext_argon2id(b"password", salt, *memory, *iterations, *parallelism, *size).is_empty());
+		}
+	}
+
 	#[test]
 	fn can_blake2b() {
 		let data = b"abc";
@@ -206,6 +1547,125 @@ pub mod tests {
 		assert_eq!(hash_64[..], expected_64[..]);
 	}
 
+	#[test]
+	fn can_blake2b_params() {
+		let data = b"abc";
+		let expected_32 = hex!("69aee57bbbc6fe807e3bef7af631552e1236b0f23a8aa56b96fac45a92ba2a35");
+		let expected_64 = hex!("8bd4accc310c6ffcdbf2290f1238b50c23d3097cb75ad5329411309f4d72216ae099affefc06745831acb0e0338ccfb443f40b51fc1324fc912605ee0e474a10");
+		let hash_32 = ext_blake2b_params(data, &[], b"this is a salt!!", b"personalization!", 32);
+		let hash_64 = ext_blake2b_params(data, b"secret key", b"salt", b"ZcashPoW", 64);
+
+		assert_eq!(hash_32[..], expected_32[..]);
+		assert_eq!(hash_64[..], expected_64[..]);
+
+		// without salt & personalization, this is the plain blake2b
+		assert_eq!(ext_blake2b_params(data, &[], &[], &[], 64), ext_blake2b(data, &[], 64));
+		assert_eq!(ext_blake2b_params(data, b"secret key", &[], &[], 32), ext_blake2b(data, b"secret key", 32));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid salt provided.")]
+	fn blake2b_params_fails_on_invalid_salt() {
+		ext_blake2b_params(b"abc", &[], &[0u8; 17], &[], 32);
+	}
+
+	#[test]
+	fn can_blake2s() {
+		let data = b"abc";
+		let expected_16 = hex!("aa4938119b1dc7b87cbad0ffd200d0ae");
+		let expected_32 = hex!("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982");
+		let key: Vec<u8> = (0..32).collect();
+		// keyed (MAC) empty input, from the BLAKE2 reference KAT
+		let expected_mac = hex!("48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49");
+
+		assert_eq!(ext_blake2s(data, &[], 16)[..], expected_16[..]);
+		assert_eq!(ext_blake2s(data, &[], 32)[..], expected_32[..]);
+		assert_eq!(ext_blake2s(&[], &key, 32)[..], expected_mac[..]);
+	}
+
+	#[test]
+	fn can_blake2s_params() {
+		let data = b"abc";
+		let expected_keyed = hex!("cb42eb2c9ea713bbdcad3ad5e44eb3684438e108d240f92a8a012ad2e36cc1bc");
+		let expected_padded = hex!("61e77cb59c01e39da70564e764a4fdc979fcf8b5c9e8869ddc630a261d155fa8");
+
+		assert_eq!(ext_blake2s_params(data, b"secret key", b"saltsalt", b"personal", 32)[..], expected_keyed[..]);
+		assert_eq!(ext_blake2s_params(data, &[], b"salt", b"pers", 32)[..], expected_padded[..]);
+		assert_eq!(ext_blake2s_params(data, b"secret key", &[], &[], 32), ext_blake2s(data, b"secret key", 32));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid size provided.")]
+	fn blake2s_fails_on_invalid_size() {
+		ext_blake2s(b"abc", &[], 33);
+	}
+
+	// input, key & context from the official BLAKE3 test_vectors.json
+	const BLAKE3_KEY: &[u8; 32] = b"whats the Elvish word for friend";
+	const BLAKE3_CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+	fn blake3_input(len: usize) -> Vec<u8> {
+		(0..len).map(|i| (i % 251) as u8).collect()
+	}
+
+	// input length, hash, keyed_hash & derive_key, all with 131-byte extended outputs
+	type Blake3Vector = (usize, Vec<u8>, Vec<u8>, Vec<u8>);
+
+	fn blake3_vectors() -> Vec<Blake3Vector> {
+		vec![
+			(
+				0,
+				hex!("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a26f5487789e8f660afe6c99ef9e0c52b92e7393024a80459cf91f476f9ffdbda7001c22e159b402631f277ca96f2defdf1078282314e763699a31c5363165421cce14d").to_vec(),
+				hex!("92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26b18171a2f22a4b94822c701f107153dba24918c4bae4d2945c20ece13387627d3b73cbf97b797d5e59948c7ef788f54372df45e45e4293c7dc18c1d41144a9758be58960856be1eabbe22c2653190de560ca3b2ac4aa692a9210694254c371e851bc8f").to_vec(),
+				hex!("2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d905630c8be290dfcf3e6842f13bddd573c098c3f17361f1f206b8cad9d088aa4a3f746752c6b0ce6a83b0da81d59649257cdf8eb3e9f7d4998e41021fac119deefb896224ac99f860011f73609e6e0e4540f93b273e56547dfd3aa1a035ba6689d89a0").to_vec()
+			),
+			(
+				1,
+				hex!("2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213c3a6cb8bf623e20cdb535f8d1a5ffb86342d9c0b64aca3bce1d31f60adfa137b358ad4d79f97b47c3d5e79f179df87a3b9776ef8325f8329886ba42f07fb138bb502f4081cbcec3195c5871e6c23e2cc97d3c69a613eba131e5f1351f3f1da786545e5").to_vec(),
+				hex!("6d7878dfff2f485635d39013278ae14f1454b8c0a3a2d34bc1ab38228a80c95b6568c0490609413006fbd428eb3fd14e7756d90f73a4725fad147f7bf70fd61c4e0cf7074885e92b0e3f125978b4154986d4fb202a3f331a3fb6cf349a3a70e49990f98fe4289761c8602c4e6ab1138d31d3b62218078b2f3ba9a88e1d08d0dd4cea11").to_vec(),
+				hex!("b3e2e340a117a499c6cf2398a19ee0d29cca2bb7404c73063382693bf66cb06c5827b91bf889b6b97c5477f535361caefca0b5d8c4746441c57617111933158950670f9aa8a05d791daae10ac683cbef8faf897c84e6114a59d2173c3f417023a35d6983f2c7dfa57e7fc559ad751dbfb9ffab39c2ef8c4aafebc9ae973a64f0c76551").to_vec()
+			),
+			(
+				1025,
+				hex!("d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444f4c4a22b4b399155358a994e52bf255de60035742ec71bd08ac275a1b51cc6bfe332b0ef84b409108cda080e6269ed4b3e2c3f7d722aa4cdc98d16deb554e5627be8f955c98e1d5f9565a9194cad0c4285f93700062d9595adb992ae68ff12800ab67a").to_vec(),
+				hex!("357dc55de0c7e382c900fd6e320acc04146be01db6a8ce7210b7189bd664ea69362396b77fdc0d2634a552970843722066c3c15902ae5097e00ff53f1e116f1cd5352720113a837ab2452cafbde4d54085d9cf5d21ca613071551b25d52e69d6c81123872b6f19cd3bc1333edf0c52b94de23ba772cf82636cff4542540a7738d5b930").to_vec(),
+				hex!("effaa245f065fbf82ac186839a249707c3bddf6d3fdda22d1b95a3c970379bcb5d31013a167509e9066273ab6e2123bc835b408b067d88f96addb550d96b6852dad38e320b9d940f86db74d398c770f462118b35d2724efa13da97194491d96dd37c3c09cbef665953f2ee85ec83d88b88d11547a6f911c8217cca46defa2751e7f3ad").to_vec()
+			),
+			(
+				2048,
+				hex!("e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a9a60bf80001410ec9eea6698cd537939fad4749edd484cb541aced55cd9bf54764d063f23f6f1e32e12958ba5cfeb1bf618ad094266d4fc3c968c2088f677454c288c67ba0dba337b9d91c7e1ba586dc9a5bc2d5e90c14f53a8863ac75655461cea8f9").to_vec(),
+				hex!("879cf1fa2ea0e79126cb1063617a05b6ad9d0b696d0d757cf053439f60a99dd10173b961cd574288194b23ece278c330fbb8585485e74967f31352a8183aa782b2b22f26cdcadb61eed1a5bc144b8198fbb0c13abbf8e3192c145d0a5c21633b0ef86054f42809df823389ee40811a5910dcbd1018af31c3b43aa55201ed4edaac74fe").to_vec(),
+				hex!("7b2945cb4fef70885cc5d78a87bf6f6207dd901ff239201351ffac04e1088a23e2c11a1ebffcea4d80447867b61badb1383d842d4e79645d48dd82ccba290769caa7af8eaa1bd78a2a5e6e94fbdab78d9c7b74e894879f6a515257ccf6f95056f4e25390f24f6b35ffbb74b766202569b1d797f2d4bd9d17524c720107f985f4ddc583").to_vec()
+			)
+		]
+	}
+
+	#[test]
+	fn can_blake3() {
+		let expected = hex!("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85");
+
+		assert_eq!(ext_blake3(b"abc", &[], 32)[..], expected[..]);
+	}
+
+	#[test]
+	fn can_blake3_vectors() {
+		for (len, hash, keyed, derived) in blake3_vectors() {
+			let input = blake3_input(len);
+
+			// the standard 32-byte output is a prefix of the extended output
+			assert_eq!(ext_blake3(&input, &[], 32)[..], hash[..32]);
+			assert_eq!(ext_blake3(&input, &[], hash.len() as u32), hash);
+			assert_eq!(ext_blake3(&input, BLAKE3_KEY, keyed.len() as u32), keyed);
+			assert_eq!(ext_blake3_derive_key(BLAKE3_CONTEXT, &input, derived.len() as u32), derived);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid key provided.")]
+	fn blake3_fails_on_invalid_key() {
+		ext_blake3(b"abc", &[0u8; 16], 32);
+	}
+
 	#[test]
 	fn can_keccak256() {
 		let data = b"test value";
@@ -215,6 +1675,90 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	// ikm, salt, info, prk & okm
+	type HkdfVector = (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>);
+
+	#[test]
+	fn can_hash160() {
+		// compressed pubkey for secret 1, the BIP173 P2WPKH example program
+		let pubkey = hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+		let expected = hex!("751e76e8199196d454941c45d1b3a323f1433bd6");
+
+		assert_eq!(ext_hash160(&pubkey)[..], expected[..]);
+		assert_eq!(ext_hash160(b"abc"), ext_ripemd160(&ext_sha256(b"abc")));
+	}
+
+	#[test]
+	fn can_hkdf_sha256() {
+		// RFC 5869, A.1 - A.3
+		let vectors: [HkdfVector; 3] = [
+			(
+				[0x0b; 22].to_vec(),
+				(0x00..0x0d).collect(),
+				(0xf0..0xfa).collect(),
+				hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5").to_vec(),
+				hex!("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865").to_vec()
+			),
+			(
+				(0x00..0x50).collect(),
+				(0x60..0xb0).collect(),
+				(0xb0..=0xff).collect(),
+				hex!("06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244").to_vec(),
+				hex!("b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87").to_vec()
+			),
+			(
+				[0x0b; 22].to_vec(),
+				vec![],
+				vec![],
+				hex!("19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04").to_vec(),
+				hex!("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8").to_vec()
+			)
+		];
+
+		for (ikm, salt, info, prk, okm) in vectors.iter() {
+			assert_eq!(ext_hkdf_sha256_extract(salt, ikm), *prk);
+			assert_eq!(ext_hkdf_sha256_expand(prk, info, okm.len() as u32), *okm);
+			assert_eq!(ext_hkdf_sha256(salt, ikm, info, okm.len() as u32), *okm);
+		}
+	}
+
+	#[test]
+	fn can_hkdf_sha512() {
+		// RFC 5869, A.1 & A.3 inputs
+		let ikm = [0x0b; 22];
+		let salt: Vec<u8> = (0x00..0x0d).collect();
+		let info: Vec<u8> = (0xf0..0xfa).collect();
+		let prk = hex!("665799823737ded04a88e47e54a5890bb2c3d247c7a4254a8e61350723590a26c36238127d8661b88cf80ef802d57e2f7cebcf1e00e083848be19929c61b4237");
+		let okm = hex!("832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb");
+		let okm_empty = hex!("f5fa02b18298a72a8c23898a8703472c6eb179dc204c03425c970e3b164bf90fff22d04836d0e2343bac");
+
+		assert_eq!(ext_hkdf_sha512_extract(&salt, &ikm)[..], prk[..]);
+		assert_eq!(ext_hkdf_sha512_expand(&prk, &info, 42)[..], okm[..]);
+		assert_eq!(ext_hkdf_sha512(&salt, &ikm, &info, 42)[..], okm[..]);
+		assert_eq!(ext_hkdf_sha512(&[], &ikm, &[], 42)[..], okm_empty[..]);
+	}
+
+	#[test]
+	fn can_hkdf_extract_as_hmac() {
+		let salt = b"salt";
+		let ikm = b"input keying material";
+
+		assert_eq!(ext_hkdf_sha256_extract(salt, ikm), ext_hmac_sha256(salt, ikm));
+		assert_eq!(ext_hkdf_sha512_extract(salt, ikm), ext_hmac_sha512(salt, ikm));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid size provided.")]
+	fn hkdf_fails_on_invalid_size() {
+		ext_hkdf_sha256(b"salt", b"ikm", b"info", 255 * 32 + 1);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid prk provided.")]
+	fn hkdf_expand_fails_on_invalid_prk() {
+		ext_hkdf_sha512_expand(&[0u8; 32], b"info", 32);
+	}
+
 	#[test]
 	fn can_hmac_sha256() {
 		let key = b"secret";
@@ -244,6 +1788,60 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	#[test]
+	fn can_sha3() {
+		let data = b"abc";
+
+		assert_eq!(ext_sha3_224(data)[..], hex!("e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf")[..]);
+		assert_eq!(ext_sha3_256(data)[..], hex!("3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")[..]);
+		assert_eq!(ext_sha3_384(data)[..], hex!("ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25")[..]);
+		assert_eq!(ext_sha3_512(data)[..], hex!("b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0")[..]);
+		assert_ne!(ext_sha3_256(data), ext_keccak256(data));
+	}
+
+	#[test]
+	fn can_shake() {
+		let data = b"abc";
+		let expected_128 = hex!("5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca578378");
+		let expected_256 = hex!("483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e41385141204f329979fd3047a13c5657724ada64d2470157b3cdc288620944d78dbcddbd9");
+
+		assert_eq!(ext_shake128(data, 64)[..], expected_128[..]);
+		assert_eq!(ext_shake256(data, 100)[..], expected_256[..]);
+
+		// shorter outputs are a prefix
+		assert_eq!(ext_shake128(data, 16)[..], expected_128[..16]);
+	}
+
+	#[test]
+	fn can_cshake() {
+		// SP 800-185 cSHAKE samples #1 & #3
+		let expected_128 = hex!("c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5");
+		let expected_256 = hex!("d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c");
+
+		assert_eq!(ext_cshake128(&hex!("00010203"), &[], b"Email Signature", 32)[..], expected_128[..]);
+		assert_eq!(ext_cshake256(&hex!("00010203"), &[], b"Email Signature", 64)[..], expected_256[..]);
+
+		// with empty name & custom, this is shake
+		assert_eq!(ext_cshake128(b"abc", &[], &[], 64), ext_shake128(b"abc", 64));
+	}
+
+	#[test]
+	fn can_kmac() {
+		// SP 800-185 KMAC samples #1, #2 & #4
+		let key = hex!("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+		let data = hex!("00010203");
+		let expected_128 = hex!("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e");
+		let expected_128_custom = hex!("3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5");
+		let expected_256 = hex!("20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd");
+
+		assert_eq!(ext_kmac128(&key, &data, &[], 32)[..], expected_128[..]);
+		assert_eq!(ext_kmac128(&key, &data, b"My Tagged Application", 32)[..], expected_128_custom[..]);
+		assert_eq!(ext_kmac256(&key, &data, b"My Tagged Application", 64)[..], expected_256[..]);
+
+		// the output size is bound into the mac
+		assert_ne!(ext_kmac128(&key, &data, &[], 16)[..], expected_128[..16]);
+	}
+
 	#[test]
 	fn can_pbkdf2() {
 		let salt = b"this is a salt";
@@ -254,6 +1852,22 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	#[test]
+	fn can_ripemd160() {
+		let vectors: [(&[u8], [u8; 20]); 4] = [
+			(b"", hex!("9c1185a5c5e9fc54612808977ee8f548b2258d31")),
+			(b"a", hex!("0bdc9d2d256b3ee9daae347be6f4dc835a467ffe")),
+			(b"abc", hex!("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc")),
+			(b"message digest", hex!("5d0689ef49d2fae572b881b123a85ffa21595f36"))
+		];
+
+		for (data, expected) in vectors.iter() {
+			assert_eq!(ext_ripemd160(data)[..], expected[..]);
+		}
+
+		assert_eq!(ext_ripemd160(&[b'a'; 1_000_000])[..], hex!("52783243c1697bdbe16d37f97f68f08325dc1528")[..]);
+	}
+
 	#[test]
 	fn can_scrypt() {
 		let password = b"password";
@@ -264,6 +1878,40 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	#[test]
+	fn can_pbkdf2_sha256() {
+		let expected_1 = hex!("120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b");
+		let expected_4096 = hex!("c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a");
+		let expected_long = hex!("348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9");
+
+		assert_eq!(ext_pbkdf2_sha256(b"password", b"salt", 1, 32)[..], expected_1[..]);
+		assert_eq!(ext_pbkdf2_sha256(b"password", b"salt", 4096, 32)[..], expected_4096[..]);
+		assert_eq!(ext_pbkdf2_sha256(b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096, 40)[..], expected_long[..]);
+	}
+
+	#[test]
+	fn can_pbkdf2_sha512() {
+		let expected = hex!("867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce");
+
+		assert_eq!(ext_pbkdf2_sha512(b"password", b"salt", 1, 64)[..], expected[..]);
+		assert_eq!(ext_pbkdf2_sha512(b"password", b"salt", 1, 32)[..], expected[..32]);
+		assert_eq!(ext_pbkdf2_sha512(b"hello world", b"this is a salt", 2048, 64), ext_pbkdf2(b"hello world", b"this is a salt", 2048));
+	}
+
+	#[test]
+	fn can_scrypt_size() {
+		let expected = hex!("fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640");
+
+		assert_eq!(ext_scrypt_size(b"password", b"NaCl", 10, 8, 16, 64)[..], expected[..]);
+		assert_eq!(ext_scrypt_size(b"password", b"NaCl", 10, 8, 16, 32)[..], expected[..32]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid scrypt hash.")]
+	fn scrypt_size_fails_on_empty_size() {
+		ext_scrypt_size(b"password", b"NaCl", 10, 8, 16, 0);
+	}
+
 	#[test]
 	fn can_sha256() {
 		let data = b"hello world";
@@ -273,6 +1921,17 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	#[test]
+	fn can_sha256d() {
+		// the genesis block header, with the hash in internal byte order
+		let header = hex!("0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c");
+		let expected_header = hex!("6fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000");
+		let expected_empty = hex!("5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456");
+
+		assert_eq!(ext_sha256d(&header)[..], expected_header[..]);
+		assert_eq!(ext_sha256d(&[])[..], expected_empty[..]);
+	}
+
 	#[test]
 	fn can_sha512() {
 		let data = b"hello world";
@@ -282,6 +1941,16 @@ pub mod tests {
 		assert_eq!(hash[..], expected[..]);
 	}
 
+	#[test]
+	fn can_tagged_hash() {
+		let expected_empty = hex!("c216d352f5818b7b4beacd4ae0a26fe888080823d2a598856661bcd54f1b3713");
+		let expected_abc = hex!("770a5b7e7c304bbcc3ea107343ff951dd404312ef418db0c3b94e2ebfbb50087");
+
+		assert_eq!(ext_tagged_hash("BIP0340/challenge", &[])[..], expected_empty[..]);
+		assert_eq!(ext_tagged_hash("BIP0340/challenge", b"abc")[..], expected_abc[..]);
+		assert_eq!(ext_tagged_hash("TapLeaf", b"abc")[..], tagged_hash(b"TapLeaf", &[b"a", b"bc"])[..]);
+	}
+
 	#[test]
 	fn can_twox() {
 		let data = b"abc";
@@ -293,4 +1962,239 @@ pub mod tests {
 		assert_eq!(hash_64[..], expected_64[..]);
 		assert_eq!(hash_256[..], expected_256[..]);
 	}
+
+	// the xxHash sanity check buffer, with prime32 & prime64 as seeds
+	const XXH_PRIME32: u64 = 2654435761;
+	const XXH_PRIME64: u64 = 11400714785074694797;
+
+	fn xxh_sanity_buffer() -> Vec<u8> {
+		let mut gen = XXH_PRIME32;
+
+		(0..2367)
+			.map(|_| {
+				let b = (gen >> 56) as u8;
+
+				gen = gen.wrapping_mul(XXH_PRIME64);
+
+				b
+			})
+			.collect()
+	}
+
+	#[test]
+	fn can_xxh64() {
+		let buffer = xxh_sanity_buffer();
+		let vectors: [(usize, u64, u64); 6] = [
+			(0, 0xef46db3751d8e999, 0xac75fda2929b17ef),
+			(1, 0xe934a84adb052768, 0x5014607643a9b4c3),
+			(12, 0x0723bf50086ead9a, 0x8252819f4e506951),
+			(48, 0xfd0feeac7a939933, 0x6ffe2f43a24c2302),
+			(222, 0xb641ae8cb691c174, 0x20cb8ab7ae10c14a),
+			(2367, 0xa82418ddec0ea581, 0xa36a93c18052673a)
+		];
+
+		for (len, expected, expected_seeded) in vectors.iter() {
+			assert_eq!(ext_xxh64(&buffer[..*len], &0u64.to_le_bytes()), expected.to_be_bytes());
+			assert_eq!(ext_xxh64(&buffer[..*len], &XXH_PRIME32.to_le_bytes()), expected_seeded.to_be_bytes());
+		}
+
+		// ext_twox is the little-endian form, seeded with the round index
+		assert_eq!(ext_xxh64(b"abc", &0u64.to_le_bytes()).iter().rev().cloned().collect::<Vec<u8>>(), ext_twox(b"abc", 1));
+	}
+
+	#[test]
+	fn can_xxh3_64() {
+		let buffer = xxh_sanity_buffer();
+		let vectors: [(usize, u64, u64); 8] = [
+			(0, 0x2d06800538d394c2, 0xa8a6b918b2f0364a),
+			(1, 0xc44bdff4074eecdb, 0x032be332dd766ef8),
+			(6, 0x27b56a84cd2d7325, 0x84589c116ab59ab9),
+			(12, 0xa713daf0dfbb77e7, 0xe7303e1b2336de0e),
+			(24, 0xa3fe70bf9d3510eb, 0x850e80fc35bdd690),
+			(195, 0xcd94217ee362ec3a, 0xba68003d370cb3d9),
+			(403, 0xcdeb804d65c6dea4, 0x6259f6ecfd6443fd),
+			(2367, 0xcb37aeb9e5d361ed, 0xd2db3415b942b42a)
+		];
+
+		for (len, expected, expected_seeded) in vectors.iter() {
+			assert_eq!(ext_xxh3_64(&buffer[..*len], &0u64.to_le_bytes()), expected.to_be_bytes());
+			assert_eq!(ext_xxh3_64(&buffer[..*len], &XXH_PRIME64.to_le_bytes()), expected_seeded.to_be_bytes());
+		}
+	}
+
+	#[test]
+	fn can_xxh3_128() {
+		let buffer = xxh_sanity_buffer();
+		let vectors: [(usize, u128, u128); 8] = [
+			(0, 0x99aa06d3014798d86001c324468d497f, 0x00feaa732a3ce25ea986dfc5d7605bfe),
+			(1, 0xa6cd5e9392000f6ac44bdff4074eecdb, 0x20e49abcc53b3842032be332dd766ef8),
+			(6, 0x082afe0b8162d12a3e7039bdda43cfc6, 0x014bd95a51ca5ddbc5b54d56038e4e40),
+			(12, 0x6e3efd8fc7802b18061a192713f69ad9, 0xff0d60acd02ed4015d92b5d7190b12d1),
+			(24, 0x0ce966e4678d37611e7044d28b1b901d, 0xd7895ded1f62559dc6cbf92a70680b19),
+			(195, 0x7729543a26b207ee3fb593c086a66075, 0x0326104c4d4849e7cf9d9ec2c8c9913f),
+			(403, 0x1b6de21e332dd73dcdeb804d65c6dea4, 0xbed311971e0be8f26259f6ecfd6443fd),
+			(2367, 0xe89c0f6ff369b427cb37aeb9e5d361ed, 0xccb7a94cca1a6496d2db3415b942b42a)
+		];
+
+		for (len, expected, expected_seeded) in vectors.iter() {
+			assert_eq!(ext_xxh3_128(&buffer[..*len], &0u64.to_le_bytes()), expected.to_be_bytes());
+			assert_eq!(ext_xxh3_128(&buffer[..*len], &XXH_PRIME64.to_le_bytes()), expected_seeded.to_be_bytes());
+		}
+	}
+
+	fn chunked(data: &[u8]) -> Vec<&[u8]> {
+		// uneven chunks, crossing the internal block boundaries
+		data.chunks(37).collect()
+	}
+
+	fn large_data() -> Vec<u8> {
+		(0..1000u32).map(|i| (i * 7) as u8).collect()
+	}
+
+	fn stream(hasher: u32, data: &[u8]) -> Vec<u8> {
+		for chunk in chunked(data) {
+			ext_hasher_update(hasher, chunk);
+		}
+
+		ext_hasher_finalize(hasher)
+	}
+
+	#[test]
+	fn can_blake2b_streaming() {
+		let data = large_data();
+
+		for (key, size) in [(&[][..], 32), (&[][..], 64), (&b"secret key"[..], 16)].iter() {
+			assert_eq!(stream(ext_blake2b_hasher_new(key, *size), &data), ext_blake2b(&data, key, *size));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid size provided.")]
+	fn blake2b_streaming_fails_on_invalid_size() {
+		ext_blake2b_hasher_new(&[], 65);
+	}
+
+	#[test]
+	fn can_blake2_params_streaming() {
+		let data = large_data();
+
+		assert_eq!(stream(ext_blake2b_params_hasher_new(b"secret key", b"salt", b"personal", 64), &data), ext_blake2b_params(&data, b"secret key", b"salt", b"personal", 64));
+		assert_eq!(stream(ext_blake2s_params_hasher_new(b"secret key", b"salt", b"personal", 32), &data), ext_blake2s_params(&data, b"secret key", b"salt", b"personal", 32));
+		assert_eq!(stream(ext_blake2s_hasher_new(b"secret key", 32), &data), ext_blake2s(&data, b"secret key", 32));
+	}
+
+	#[test]
+	fn can_blake3_streaming() {
+		for (len, hash, keyed, derived) in blake3_vectors() {
+			let input = blake3_input(len);
+
+			assert_eq!(stream(ext_blake3_hasher_new(&[], hash.len() as u32), &input), hash);
+			assert_eq!(stream(ext_blake3_hasher_new(BLAKE3_KEY, keyed.len() as u32), &input), keyed);
+			assert_eq!(stream(ext_blake3_derive_key_hasher_new(BLAKE3_CONTEXT, derived.len() as u32), &input), derived);
+		}
+	}
+
+	#[test]
+	fn can_keccak_streaming() {
+		let data = large_data();
+
+		assert_eq!(stream(ext_keccak256_hasher_new(), &data), ext_keccak256(&data));
+		assert_eq!(stream(ext_keccak512_hasher_new(), &data), ext_keccak512(&data));
+	}
+
+	#[test]
+	fn can_sha3_streaming() {
+		let data = large_data();
+		let key = b"secret key";
+
+		assert_eq!(stream(ext_sha3_224_hasher_new(), &data), ext_sha3_224(&data));
+		assert_eq!(stream(ext_sha3_256_hasher_new(), &data), ext_sha3_256(&data));
+		assert_eq!(stream(ext_sha3_384_hasher_new(), &data), ext_sha3_384(&data));
+		assert_eq!(stream(ext_sha3_512_hasher_new(), &data), ext_sha3_512(&data));
+		assert_eq!(stream(ext_shake128_hasher_new(200), &data), ext_shake128(&data, 200));
+		assert_eq!(stream(ext_shake256_hasher_new(200), &data), ext_shake256(&data, 200));
+		assert_eq!(stream(ext_cshake128_hasher_new(&[], b"custom", 64), &data), ext_cshake128(&data, &[], b"custom", 64));
+		assert_eq!(stream(ext_cshake256_hasher_new(&[], b"custom", 64), &data), ext_cshake256(&data, &[], b"custom", 64));
+		assert_eq!(stream(ext_kmac128_hasher_new(key, b"custom", 32), &data), ext_kmac128(key, &data, b"custom", 32));
+		assert_eq!(stream(ext_kmac256_hasher_new(key, b"custom", 64), &data), ext_kmac256(key, &data, b"custom", 64));
+	}
+
+	#[test]
+	fn can_sha_streaming() {
+		let data = large_data();
+
+		assert_eq!(stream(ext_sha256_hasher_new(), &data), ext_sha256(&data));
+		assert_eq!(stream(ext_sha512_hasher_new(), &data), ext_sha512(&data));
+	}
+
+	#[test]
+	fn can_bitcoin_streaming() {
+		let data = large_data();
+
+		assert_eq!(stream(ext_hash160_hasher_new(), &data), ext_hash160(&data));
+		assert_eq!(stream(ext_ripemd160_hasher_new(), &data), ext_ripemd160(&data));
+		assert_eq!(stream(ext_sha256d_hasher_new(), &data), ext_sha256d(&data));
+		assert_eq!(stream(ext_tagged_hasher_new("TapLeaf"), &data), ext_tagged_hash("TapLeaf", &data));
+	}
+
+	#[test]
+	fn can_twox_streaming() {
+		let data = large_data();
+
+		for rounds in [1, 2, 4].iter() {
+			assert_eq!(stream(ext_twox_hasher_new(*rounds), &data), ext_twox(&data, *rounds));
+		}
+	}
+
+	#[test]
+	fn can_xxh_streaming() {
+		let data = xxh_sanity_buffer();
+
+		for seed in [0, XXH_PRIME32, XXH_PRIME64].iter() {
+			let seed = seed.to_le_bytes();
+
+			assert_eq!(stream(ext_xxh64_hasher_new(&seed), &data), ext_xxh64(&data, &seed));
+			assert_eq!(stream(ext_xxh3_hasher_new(&seed, 8), &data), ext_xxh3_64(&data, &seed));
+			assert_eq!(stream(ext_xxh3_hasher_new(&seed, 16), &data), ext_xxh3_128(&data, &seed));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid seed provided.")]
+	fn xxh64_fails_on_invalid_seed() {
+		ext_xxh64(b"abc", &[0u8; 4]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid size provided.")]
+	fn xxh3_streaming_fails_on_invalid_size() {
+		ext_xxh3_hasher_new(&[0u8; 8], 32);
+	}
+
+	#[test]
+	fn can_streaming_empty() {
+		assert_eq!(ext_hasher_finalize(ext_blake2b_hasher_new(&[], 32)), ext_blake2b(&[], &[], 32));
+		assert_eq!(ext_hasher_finalize(ext_keccak256_hasher_new()), ext_keccak256(&[]));
+		assert_eq!(ext_hasher_finalize(ext_sha256_hasher_new()), ext_sha256(&[]));
+		assert_eq!(ext_hasher_finalize(ext_twox_hasher_new(2)), ext_twox(&[], 2));
+	}
+
+	#[test]
+	fn can_free_hasher() {
+		let hasher = ext_sha256_hasher_new();
+
+		ext_hasher_update(hasher, b"abc");
+
+		assert!(ext_hasher_free(hasher));
+		assert!(!ext_hasher_free(hasher));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid handle provided.")]
+	fn hasher_fails_after_finalize() {
+		let hasher = ext_sha256_hasher_new();
+
+		ext_hasher_finalize(hasher);
+		ext_hasher_update(hasher, b"abc");
+	}
 }
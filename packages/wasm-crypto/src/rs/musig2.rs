@@ -0,0 +1,1076 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use k256::{elliptic_curve::{ops::Reduce, point::AffineCoordinates, sec1::ToEncodedPoint, PrimeField}, AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar, U256};
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use crate::{handles::{self, Handles}, hashing::tagged_hash};
+
+/// Size of a compressed participant public key, bytes
+pub const PUBKEY_SIZE: usize = 33;
+
+/// Size of a public nonce (and aggregate nonce), two compressed points
+pub const PUBNONCE_SIZE: usize = 66;
+
+/// Size of a partial signature, bytes
+pub const PSIG_SIZE: usize = 32;
+
+/// The secret nonce, only ever held inside the session
+struct SecNonce {
+	k1: Scalar,
+	k2: Scalar,
+	pubkey: [u8; PUBKEY_SIZE],
+}
+
+/// The values derived from the aggregate nonce and message for a signing session
+struct SessionValues {
+	b: Scalar,
+	e: Scalar,
+	r: AffinePoint,
+}
+
+/// Interpret 32 bytes as an integer, reduced modulo the curve order
+fn scalar_reduce(bytes: &[u8; 32]) -> Scalar {
+	<Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(bytes))
+}
+
+/// Interpret 32 bytes as an integer, failing when not below the curve order
+fn scalar_strict(bytes: &[u8]) -> Option<Scalar> {
+	match bytes.len() {
+		32 => Scalar::from_repr(*FieldBytes::from_slice(bytes)).into(),
+		_ => None
+	}
+}
+
+/// Returns the scalar as 32 big-endian bytes
+fn scalar_bytes(s: &Scalar) -> [u8; 32] {
+	let mut res = [0u8; 32];
+
+	res.copy_from_slice(&s.to_bytes());
+
+	res
+}
+
+/// Parse a 33-byte compressed point, rejecting the point at infinity
+fn cpoint(bytes: &[u8]) -> Option<ProjectivePoint> {
+	match bytes.len() {
+		PUBKEY_SIZE => PublicKey::from_sec1_bytes(bytes)
+			.ok()
+			.map(|p| p.to_projective()),
+		_ => None
+	}
+}
+
+/// Parse a 33-byte compressed point, with 33 zero bytes as the point at infinity
+fn cpoint_ext(bytes: &[u8]) -> Option<ProjectivePoint> {
+	if bytes.len() == PUBKEY_SIZE && bytes.iter().all(|b| *b == 0) {
+		Some(ProjectivePoint::IDENTITY)
+	} else {
+		cpoint(bytes)
+	}
+}
+
+/// Serialize a point as 33 compressed bytes (the caller ensures it is not infinity)
+fn cbytes(p: &ProjectivePoint) -> [u8; PUBKEY_SIZE] {
+	let mut res = [0u8; PUBKEY_SIZE];
+
+	res.copy_from_slice(p.to_affine().to_encoded_point(true).as_bytes());
+
+	res
+}
+
+/// Serialize a point as 33 compressed bytes, with infinity as 33 zero bytes
+fn cbytes_ext(p: &ProjectivePoint) -> [u8; PUBKEY_SIZE] {
+	if *p == ProjectivePoint::IDENTITY {
+		[0u8; PUBKEY_SIZE]
+	} else {
+		cbytes(p)
+	}
+}
+
+/// Serialize the x coordinate of a point, 32 bytes
+fn xbytes(p: &AffinePoint) -> [u8; 32] {
+	let mut res = [0u8; 32];
+
+	res.copy_from_slice(&p.x());
+
+	res
+}
+
+/// Returns true when the point has an even y coordinate
+fn has_even_y(p: &AffinePoint) -> bool {
+	!bool::from(p.y_is_odd())
+}
+
+/// Returns 1 for points with even y, -1 otherwise
+fn parity_factor(p: &AffinePoint) -> Scalar {
+	if has_even_y(p) {
+		Scalar::ONE
+	} else {
+		-Scalar::ONE
+	}
+}
+
+/// Split concatenated 33-byte public keys
+fn split_pubkeys(pubkeys: &[u8]) -> Option<Vec<[u8; PUBKEY_SIZE]>> {
+	if pubkeys.is_empty() || pubkeys.len() % PUBKEY_SIZE != 0 {
+		return None;
+	}
+
+	let keys: Vec<[u8; PUBKEY_SIZE]> = pubkeys
+		.chunks(PUBKEY_SIZE)
+		.map(|c| {
+			let mut k = [0u8; PUBKEY_SIZE];
+
+			k.copy_from_slice(c);
+
+			k
+		})
+		.collect();
+
+	match keys.iter().all(|k| cpoint(k).is_some()) {
+		true => Some(keys),
+		false => None
+	}
+}
+
+/// BIP327 NonceGen with the caller-supplied random input
+///
+/// The optional inputs follow the BIP, i.e. an absent secret key, aggregate key
+/// or message is not mixed into the nonce. Returns the secret nonce along with
+/// the 66-byte public nonce.
+fn nonce_gen_internal(rand: &[u8; 32], seckey: Option<&[u8]>, pubkey: &[u8; PUBKEY_SIZE], aggpk: Option<&[u8]>, message: Option<&[u8]>, extra: &[u8]) -> Option<(SecNonce, Vec<u8>)> {
+	let rand: Vec<u8> = match seckey {
+		Some(seckey) if seckey.len() == 32 => seckey
+			.iter()
+			.zip(tagged_hash(b"MuSig/aux", &[rand]).iter())
+			.map(|(a, b)| a ^ b)
+			.collect(),
+		Some(_) => return None,
+		None => rand.to_vec()
+	};
+	let aggpk = aggpk.unwrap_or(&[]);
+	let message: Vec<u8> = match message {
+		Some(m) => [&[1u8][..], &(m.len() as u64).to_be_bytes(), m].concat(),
+		None => vec![0]
+	};
+	let xlen = (extra.len() as u32).to_be_bytes();
+	let k = |i: u8| scalar_reduce(&tagged_hash(b"MuSig/nonce", &[
+		&rand,
+		&[PUBKEY_SIZE as u8],
+		pubkey,
+		&[aggpk.len() as u8],
+		aggpk,
+		&message,
+		&xlen,
+		extra,
+		&[i]
+	]));
+	let k1 = k(0);
+	let k2 = k(1);
+
+	if k1 == Scalar::ZERO || k2 == Scalar::ZERO {
+		return None;
+	}
+
+	let pubnonce = [cbytes(&(ProjectivePoint::GENERATOR * k1)), cbytes(&(ProjectivePoint::GENERATOR * k2))].concat();
+
+	Some((SecNonce { k1, k2, pubkey: *pubkey }, pubnonce))
+}
+
+/// BIP327 key aggregation and signing session
+///
+/// The session is created from the ordered list of participant public keys.
+/// The secret nonce generated via nonce_gen is held internally and is cleared
+/// on the first call to sign, so it can never be used for two signatures.
+struct MuSig2Session {
+	pubkeys: Vec<[u8; PUBKEY_SIZE]>,
+	list_hash: [u8; 32],
+	second: Option<[u8; PUBKEY_SIZE]>,
+	q: ProjectivePoint,
+	gacc: Scalar,
+	tacc: Scalar,
+	secnonce: Option<SecNonce>,
+}
+
+impl MuSig2Session {
+	/// Create a session via BIP327 KeyAgg
+	fn new(pubkeys: &[u8]) -> Result<MuSig2Session, &'static str> {
+		let keys = split_pubkeys(pubkeys).ok_or("Invalid pubkeys provided.")?;
+		let list_hash = tagged_hash(b"KeyAgg list", &[pubkeys]);
+		let second = keys
+			.iter()
+			.find(|k| k[..] != keys[0][..])
+			.copied();
+		let mut session = MuSig2Session {
+			pubkeys: keys,
+			list_hash,
+			second,
+			q: ProjectivePoint::IDENTITY,
+			gacc: Scalar::ONE,
+			tacc: Scalar::ZERO,
+			secnonce: None,
+		};
+
+		for k in session.pubkeys.iter() {
+			// all keys are in the list, so the coefficient is always available
+			let a = session.key_agg_coeff(k).unwrap();
+
+			session.q += cpoint(k).unwrap() * a;
+		}
+
+		if session.q == ProjectivePoint::IDENTITY {
+			return Err("Invalid aggregate pubkey.");
+		}
+
+		Ok(session)
+	}
+
+	/// KeyAggCoeff for a participant key, None when not part of the session
+	fn key_agg_coeff(&self, pubkey: &[u8]) -> Option<Scalar> {
+		if !self.pubkeys.iter().any(|k| k[..] == pubkey[..]) {
+			None
+		} else if self.second.map_or(false, |k| k[..] == pubkey[..]) {
+			Some(Scalar::ONE)
+		} else {
+			Some(scalar_reduce(&tagged_hash(b"KeyAgg coefficient", &[&self.list_hash, pubkey])))
+		}
+	}
+
+	/// The aggregate key as affine point
+	fn q_affine(&self) -> AffinePoint {
+		self.q.to_affine()
+	}
+
+	/// The x-only aggregate (tweaked) key
+	fn agg_pubkey(&self) -> [u8; 32] {
+		xbytes(&self.q_affine())
+	}
+
+	/// Apply a plain or x-only tweak to the aggregate key, leaving the session untouched on failure
+	fn apply_tweak(&mut self, tweak: &[u8], xonly: bool) -> Result<(), &'static str> {
+		let t = scalar_strict(tweak).ok_or("Invalid tweak provided.")?;
+		let g = match xonly {
+			true => parity_factor(&self.q_affine()),
+			false => Scalar::ONE
+		};
+		let q = self.q * g + ProjectivePoint::GENERATOR * t;
+
+		if q == ProjectivePoint::IDENTITY {
+			return Err("Invalid tweak provided.");
+		}
+
+		self.q = q;
+		self.gacc = g * self.gacc;
+		self.tacc = t + g * self.tacc;
+
+		Ok(())
+	}
+
+	/// GetSessionValues for the aggregate nonce and message
+	fn session_values(&self, aggnonce: &[u8], message: &[u8]) -> Option<SessionValues> {
+		if aggnonce.len() != PUBNONCE_SIZE {
+			return None;
+		}
+
+		let qx = self.agg_pubkey();
+		let b = scalar_reduce(&tagged_hash(b"MuSig/noncecoef", &[aggnonce, &qx, message]));
+		let r1 = cpoint_ext(&aggnonce[..PUBKEY_SIZE])?;
+		let r2 = cpoint_ext(&aggnonce[PUBKEY_SIZE..])?;
+		let r = match r1 + r2 * b {
+			p if p == ProjectivePoint::IDENTITY => ProjectivePoint::GENERATOR,
+			p => p
+		}.to_affine();
+		let e = scalar_reduce(&tagged_hash(b"BIP0340/challenge", &[&xbytes(&r), &qx, message]));
+
+		Some(SessionValues { b, e, r })
+	}
+
+	/// NonceGen for a participant of the session, storing the secret nonce
+	fn nonce_gen(&mut self, rand: &[u8; 32], seckey: &[u8], message: &[u8], extra: &[u8]) -> Result<Vec<u8>, &'static str> {
+		let pubkey = match scalar_strict(seckey) {
+			Some(d) if d != Scalar::ZERO => cbytes(&(ProjectivePoint::GENERATOR * d)),
+			_ => return Err("Invalid secret provided.")
+		};
+
+		if self.key_agg_coeff(&pubkey).is_none() {
+			return Err("Invalid secret provided.");
+		}
+
+		let (secnonce, pubnonce) = nonce_gen_internal(rand, Some(seckey), &pubkey, Some(&self.agg_pubkey()), Some(message), extra)
+			.ok_or("Unable to generate nonce.")?;
+
+		self.secnonce = Some(secnonce);
+
+		Ok(pubnonce)
+	}
+
+	/// BIP327 Sign, consuming the session nonce (also when signing fails)
+	fn sign(&mut self, seckey: &[u8], aggnonce: &[u8], message: &[u8]) -> Result<Vec<u8>, &'static str> {
+		// take the nonce before anything else, it is never available again
+		let secnonce = self.secnonce.take().ok_or("No unused nonce available.")?;
+
+		if secnonce.k1 == Scalar::ZERO || secnonce.k2 == Scalar::ZERO {
+			return Err("Invalid secnonce provided.");
+		}
+
+		let d = match scalar_strict(seckey) {
+			Some(d) if d != Scalar::ZERO => d,
+			_ => return Err("Invalid secret provided.")
+		};
+		let pubkey = cbytes(&(ProjectivePoint::GENERATOR * d));
+
+		if pubkey != secnonce.pubkey {
+			return Err("Invalid secret provided.");
+		}
+
+		let values = self.session_values(aggnonce, message).ok_or("Invalid aggnonce provided.")?;
+		let a = self.key_agg_coeff(&pubkey).ok_or("Invalid secret provided.")?;
+		let (k1, k2) = match has_even_y(&values.r) {
+			true => (secnonce.k1, secnonce.k2),
+			false => (-secnonce.k1, -secnonce.k2)
+		};
+		let d = parity_factor(&self.q_affine()) * self.gacc * d;
+		let s = k1 + values.b * k2 + values.e * a * d;
+		let psig = scalar_bytes(&s);
+		let pubnonce = [cbytes(&(ProjectivePoint::GENERATOR * secnonce.k1)), cbytes(&(ProjectivePoint::GENERATOR * secnonce.k2))].concat();
+
+		if !self.partial_verify_internal(&psig, &pubnonce, &pubkey, &values) {
+			return Err("Unable to sign.");
+		}
+
+		Ok(psig.to_vec())
+	}
+
+	/// BIP327 PartialSigVerify against the aggregate nonce
+	fn partial_verify(&self, psig: &[u8], pubnonce: &[u8], pubkey: &[u8], aggnonce: &[u8], message: &[u8]) -> bool {
+		match self.session_values(aggnonce, message) {
+			Some(v) => self.partial_verify_internal(psig, pubnonce, pubkey, &v),
+			_ => false
+		}
+	}
+
+	/// PartialSigVerifyInternal, the partial signature against the signer's public nonce and key
+	fn partial_verify_internal(&self, psig: &[u8], pubnonce: &[u8], pubkey: &[u8], values: &SessionValues) -> bool {
+		let s = match scalar_strict(psig) {
+			Some(s) => s,
+			None => return false
+		};
+		let (r1, r2, p) = match (pubnonce.len(), cpoint(pubkey)) {
+			(PUBNONCE_SIZE, Some(p)) => match (cpoint(&pubnonce[..PUBKEY_SIZE]), cpoint(&pubnonce[PUBKEY_SIZE..])) {
+				(Some(r1), Some(r2)) => (r1, r2, p),
+				_ => return false
+			},
+			_ => return false
+		};
+		let a = match self.key_agg_coeff(pubkey) {
+			Some(a) => a,
+			None => return false
+		};
+		let re = match has_even_y(&values.r) {
+			true => r1 + r2 * values.b,
+			false => -(r1 + r2 * values.b)
+		};
+		let g = parity_factor(&self.q_affine()) * self.gacc;
+
+		ProjectivePoint::GENERATOR * s == re + p * (values.e * a * g)
+	}
+
+	/// BIP327 PartialSigAgg
+	fn aggregate(&self, psigs: &[u8], aggnonce: &[u8], message: &[u8]) -> Result<Vec<u8>, &'static str> {
+		if psigs.is_empty() || psigs.len() % PSIG_SIZE != 0 {
+			return Err("Invalid psigs provided.");
+		}
+
+		let values = self.session_values(aggnonce, message).ok_or("Invalid aggnonce provided.")?;
+		let s = psigs
+			.chunks(PSIG_SIZE)
+			.try_fold(Scalar::ZERO, |s, p| scalar_strict(p).map(|p| s + p))
+			.ok_or("Invalid psigs provided.")?;
+		let s = s + values.e * parity_factor(&self.q_affine()) * self.tacc;
+
+		Ok([xbytes(&values.r), scalar_bytes(&s)].concat())
+	}
+}
+
+thread_local! {
+	static SESSIONS: RefCell<Handles<MuSig2Session>> = RefCell::new(Handles::new());
+}
+
+/// Apply the function to the session behind the handle, panicking on errors
+fn with_session<R>(handle: u32, f: impl FnOnce(&mut MuSig2Session) -> Result<R, &'static str>) -> R {
+	match handles::with(&SESSIONS, handle, f) {
+		Ok(r) => r,
+		Err(e) => panic!("{}", e)
+	}
+}
+
+/// Create a session via BIP327 KeyAgg
+///
+/// * pubkeys: UIntArray with 33 * n elements, the ordered participant keys
+///
+/// returned value is the session handle, the order of keys is significant,
+/// use ext_musig2_key_sort for a canonical order
+#[wasm_bindgen]
+pub fn ext_musig2_session_new(pubkeys: &[u8]) -> u32 {
+	match MuSig2Session::new(pubkeys) {
+		Ok(s) => handles::insert(&SESSIONS, s),
+		Err(e) => panic!("{}", e)
+	}
+}
+
+/// Apply a plain or x-only (e.g. BIP341 taproot) tweak to the aggregate key
+///
+/// * handle: the session handle
+/// * tweak: UIntArray with 32 element
+/// * xonly: true for x-only tweaking, false for plain tweaking
+///
+/// returned vector is the x-only aggregate key after tweaking, 32 bytes
+#[wasm_bindgen]
+pub fn ext_musig2_session_tweak(handle: u32, tweak: &[u8], xonly: bool) -> Vec<u8> {
+	with_session(handle, |s| {
+		s.apply_tweak(tweak, xonly)?;
+
+		Ok(s.agg_pubkey().to_vec())
+	})
+}
+
+/// Returns the x-only aggregate (tweaked) key, 32 bytes
+///
+/// * handle: the session handle
+///
+/// this is the BIP340 key that the aggregate signature verifies against
+#[wasm_bindgen]
+pub fn ext_musig2_session_agg_pubkey(handle: u32) -> Vec<u8> {
+	with_session(handle, |s| Ok(s.agg_pubkey().to_vec()))
+}
+
+/// Returns the plain aggregate (tweaked) key, 33 bytes
+///
+/// * handle: the session handle
+#[wasm_bindgen]
+pub fn ext_musig2_session_agg_pubkey_full(handle: u32) -> Vec<u8> {
+	with_session(handle, |s| Ok(cbytes(&s.q).to_vec()))
+}
+
+/// Generate the signer nonce via BIP327 NonceGen
+///
+/// * handle: the session handle
+/// * seckey: UIntArray with 32 element, the signer secret key
+/// * message: Arbitrary length UIntArray, the message to be signed
+/// * extra: Arbitrary length UIntArray, optional additional input (may be empty)
+///
+/// returned vector is the public nonce of 66 bytes, the secret nonce is kept
+/// in the session and replaces any previously generated (unused) nonce
+#[wasm_bindgen]
+pub fn ext_musig2_session_nonce_gen(handle: u32, seckey: &[u8], message: &[u8], extra: &[u8]) -> Vec<u8> {
+	let mut rand = [0u8; 32];
+
+	getrandom::getrandom(&mut rand).expect("Unable to retrieve randomness.");
+
+	with_session(handle, |s| s.nonce_gen(&rand, seckey, message, extra))
+}
+
+/// Create a partial signature via BIP327 Sign, consuming the session nonce
+///
+/// * handle: the session handle
+/// * seckey: UIntArray with 32 element, the secret key used in nonce_gen
+/// * aggnonce: UIntArray with 66 element, the aggregate nonce
+/// * message: Arbitrary length UIntArray, the message to be signed
+///
+/// returned vector is the partial signature of 32 bytes
+#[wasm_bindgen]
+pub fn ext_musig2_session_sign(handle: u32, seckey: &[u8], aggnonce: &[u8], message: &[u8]) -> Vec<u8> {
+	with_session(handle, |s| s.sign(seckey, aggnonce, message))
+}
+
+/// Verify a partial signature via BIP327 PartialSigVerify
+///
+/// * handle: the session handle
+/// * psig: UIntArray with 32 element
+/// * pubnonce: UIntArray with 66 element, the public nonce of the signer
+/// * pubkey: UIntArray with 33 element, the public key of the signer
+/// * aggnonce: UIntArray with 66 element, the aggregate nonce
+/// * message: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_musig2_session_partial_verify(handle: u32, psig: &[u8], pubnonce: &[u8], pubkey: &[u8], aggnonce: &[u8], message: &[u8]) -> bool {
+	with_session(handle, |s| Ok(s.partial_verify(psig, pubnonce, pubkey, aggnonce, message)))
+}
+
+/// Aggregate the partial signatures via BIP327 PartialSigAgg
+///
+/// * handle: the session handle
+/// * psigs: UIntArray with 32 * n elements, the concatenated partial signatures
+/// * aggnonce: UIntArray with 66 element, the aggregate nonce
+/// * message: Arbitrary length UIntArray
+///
+/// returned vector is the BIP340 signature of 64 bytes
+#[wasm_bindgen]
+pub fn ext_musig2_session_aggregate(handle: u32, psigs: &[u8], aggnonce: &[u8], message: &[u8]) -> Vec<u8> {
+	with_session(handle, |s| s.aggregate(psigs, aggnonce, message))
+}
+
+/// Release the session (along with any unused secret nonce)
+///
+/// * handle: the session handle
+///
+/// returns true when the handle referred to a session
+#[wasm_bindgen]
+pub fn ext_musig2_session_free(handle: u32) -> bool {
+	handles::remove(&SESSIONS, handle).is_some()
+}
+
+/// BIP327 NonceAgg
+fn nonce_agg(pubnonces: &[u8]) -> Result<Vec<u8>, &'static str> {
+	if pubnonces.is_empty() || pubnonces.len() % PUBNONCE_SIZE != 0 {
+		return Err("Invalid pubnonces provided.");
+	}
+
+	let mut r1 = ProjectivePoint::IDENTITY;
+	let mut r2 = ProjectivePoint::IDENTITY;
+
+	for n in pubnonces.chunks(PUBNONCE_SIZE) {
+		match (cpoint(&n[..PUBKEY_SIZE]), cpoint(&n[PUBKEY_SIZE..])) {
+			(Some(a), Some(b)) => {
+				r1 += a;
+				r2 += b;
+			},
+			_ => return Err("Invalid pubnonces provided.")
+		}
+	}
+
+	Ok([cbytes_ext(&r1), cbytes_ext(&r2)].concat())
+}
+
+/// Sort public keys via BIP327 KeySort
+///
+/// * pubkeys: UIntArray with 33 * n elements
+///
+/// returned vector is the lexicographically sorted keys, 33 * n bytes
+#[wasm_bindgen]
+pub fn ext_musig2_key_sort(pubkeys: &[u8]) -> Vec<u8> {
+	match split_pubkeys(pubkeys) {
+		Some(mut keys) => {
+			keys.sort_unstable();
+
+			keys.concat()
+		},
+		_ => panic!("Invalid pubkeys provided.")
+	}
+}
+
+/// Aggregate public nonces via BIP327 NonceAgg
+///
+/// * pubnonces: UIntArray with 66 * n elements, the concatenated public nonces
+///
+/// returned vector is the aggregate nonce of 66 bytes
+#[wasm_bindgen]
+pub fn ext_musig2_nonce_agg(pubnonces: &[u8]) -> Vec<u8> {
+	match nonce_agg(pubnonces) {
+		Ok(n) => n,
+		Err(e) => panic!("{}", e)
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	use hex_literal::hex;
+	use super::*;
+	use crate::secp256k1::{ext_secp_from_seed, ext_secp_schnorr_verify};
+
+	const PUBKEYS: [[u8; 33]; 3] = [
+		hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+		hex!("03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+		hex!("023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66"),
+	];
+
+	// BIP327 sign_verify_vectors.json
+	const SV_SECKEY: [u8; 32] = hex!("7fb9e0e687ada1eebf7ecfe2f21e73ebdb51a7d450948dfe8d76d7f2d1007671");
+	const SV_SECNONCE: [u8; 97] = hex!("508b81a611f100a6b2b6b29656590898af488bcf2e1f55cf22e5cfb84421fe61fa27fd49b1d50085b481285e1ca205d55c82cc1b31ff5cd54a489829355901f703935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9");
+	const SV_MESSAGE: [u8; 32] = hex!("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+	const SV_PUBKEYS: [[u8; 33]; 4] = [
+		hex!("03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9"),
+		hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+		hex!("02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba661"),
+		hex!("020000000000000000000000000000000000000000000000000000000000000007"),
+	];
+	const SV_PUBNONCES: [[u8; 66]; 5] = [
+		hex!("0337c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480"),
+		hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f817980279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+		hex!("032de2662628c90b03f5e720284eb52ff7d71f4284f627b68a853d78c78e1ffe9303e4c5524e83ffe1493b9077cf1ca6beb2090c93d930321071ad40b2f44e599046"),
+		hex!("0237c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0387bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480"),
+		hex!("0200000000000000000000000000000000000000000000000000000000000000090287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480"),
+	];
+	const SV_AGGNONCES: [[u8; 66]; 5] = [
+		hex!("028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9"),
+		hex!("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+		hex!("048465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9"),
+		hex!("028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61020000000000000000000000000000000000000000000000000000000000000009"),
+		hex!("028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd6102fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30"),
+	];
+
+	// (seckey, pubkey, aggpk, message, extra, secnonce, pubnonce)
+	type NonceGenCase<'a> = (Option<&'a [u8]>, [u8; 33], Option<&'a [u8]>, Option<&'a [u8]>, &'a [u8], [u8; 97], [u8; 66]);
+
+	// (key indices, nonce indices, aggnonce index, signer index, psig)
+	type SignCase<'a> = (&'a [usize], &'a [usize], usize, usize, [u8; 32]);
+
+	// (tweak index, xonly)
+	type Tweak = (usize, bool);
+
+	// (key indices, tweaks, aggnonce, psig indices, signature or error)
+	type SigAggCase<'a> = (&'a [usize], &'a [Tweak], [u8; 66], &'a [usize], Result<[u8; 64], &'a str>);
+
+	fn concat_indexed<const N: usize>(items: &[[u8; N]], indices: &[usize]) -> Vec<u8> {
+		indices
+			.iter()
+			.flat_map(|i| items[*i].to_vec())
+			.collect()
+	}
+
+	fn secnonce_from_bytes(bytes: &[u8; 97]) -> SecNonce {
+		let mut k1 = [0u8; 32];
+		let mut k2 = [0u8; 32];
+		let mut pubkey = [0u8; PUBKEY_SIZE];
+
+		k1.copy_from_slice(&bytes[..32]);
+		k2.copy_from_slice(&bytes[32..64]);
+		pubkey.copy_from_slice(&bytes[64..]);
+
+		SecNonce { k1: scalar_reduce(&k1), k2: scalar_reduce(&k2), pubkey }
+	}
+
+	fn secnonce_to_bytes(secnonce: &SecNonce) -> Vec<u8> {
+		[&scalar_bytes(&secnonce.k1)[..], &scalar_bytes(&secnonce.k2), &secnonce.pubkey].concat()
+	}
+
+	fn create_signers(count: u8) -> (Vec<[u8; 32]>, Vec<u8>) {
+		let seckeys: Vec<[u8; 32]> = (1..=count).map(|i| [i; 32]).collect();
+		let pubkeys: Vec<u8> = seckeys
+			.iter()
+			.flat_map(|s| ext_secp_from_seed(s)[32..].to_vec())
+			.collect();
+
+		(seckeys, ext_musig2_key_sort(&pubkeys))
+	}
+
+	fn sign_all(seckeys: &[[u8; 32]], pubkeys: &[u8], tweak: Option<&[u8]>, message: &[u8]) -> (u32, Vec<u8>, Vec<u8>) {
+		let handles: Vec<u32> = seckeys
+			.iter()
+			.map(|_| {
+				let handle = ext_musig2_session_new(pubkeys);
+
+				if let Some(t) = tweak {
+					ext_musig2_session_tweak(handle, t, true);
+				}
+
+				handle
+			})
+			.collect();
+		let pubnonces: Vec<u8> = handles
+			.iter()
+			.zip(seckeys.iter())
+			.flat_map(|(h, k)| ext_musig2_session_nonce_gen(*h, k, message, &[]))
+			.collect();
+		let aggnonce = ext_musig2_nonce_agg(&pubnonces);
+		let psigs: Vec<u8> = handles
+			.iter()
+			.zip(seckeys.iter())
+			.flat_map(|(h, k)| ext_musig2_session_sign(*h, k, &aggnonce, message))
+			.collect();
+
+		for h in handles[1..].iter() {
+			assert!(ext_musig2_session_free(*h));
+		}
+
+		(handles[0], aggnonce, [psigs, pubnonces].concat())
+	}
+
+	#[test]
+	fn can_key_agg() {
+		let cases: [(&[usize], [u8; 32]); 4] = [
+			(&[0, 1, 2], hex!("90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c")),
+			(&[2, 1, 0], hex!("6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b")),
+			(&[0, 0, 0], hex!("b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935")),
+			(&[0, 0, 1, 1], hex!("69bc22bfa5d106306e48a20679de1d7389386124d07571d0d872686028c26a3e")),
+		];
+
+		for (indices, expected) in cases.iter() {
+			let handle = ext_musig2_session_new(&concat_indexed(&PUBKEYS, indices));
+			let res = ext_musig2_session_agg_pubkey(handle);
+
+			assert_eq!(res[..], expected[..]);
+			assert!(ext_musig2_session_free(handle));
+		}
+	}
+
+	#[test]
+	fn can_key_sort() {
+		let pubkeys = [PUBKEYS[0], PUBKEYS[1], PUBKEYS[2]].concat();
+		let expected = [PUBKEYS[2], PUBKEYS[0], PUBKEYS[1]].concat();
+		let res = ext_musig2_key_sort(&pubkeys);
+
+		assert_eq!(res[..], expected[..]);
+	}
+
+	#[test]
+	fn can_nonce_gen_vectors() {
+		// BIP327 nonce_gen_vectors.json
+		let rand = hex!("0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f");
+		let seckey = hex!("0202020202020202020202020202020202020202020202020202020202020202");
+		let aggpk = hex!("0707070707070707070707070707070707070707070707070707070707070707");
+		let message = hex!("0101010101010101010101010101010101010101010101010101010101010101");
+		let extra = hex!("0808080808080808080808080808080808080808080808080808080808080808");
+		let cases: [NonceGenCase; 2] = [
+			(
+				Some(&seckey),
+				hex!("024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"),
+				Some(&aggpk),
+				Some(&message),
+				&extra,
+				hex!("b114e502beaa4e301dd08a50264172c84e41650e6cb726b410c0694d59effb6495b5caf28d045b973d63e3c99a44b807bde375fd6cb39e46dc4a511708d0e9d2024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"),
+				hex!("02f7be7089e8376eb355272368766b17e88e7db72047d05e56aa881ea52b3b35df02c29c8046fdd0ded4c7e55869137200fbdbfe2eb654267b6d7013602caed3115a")
+			),
+			(
+				None,
+				hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+				None,
+				None,
+				&[],
+				hex!("89bdd787d0284e5e4d5fc572e49e316bab7e21e3b1830de37dfe80156fa41a6d0b17ae8d024c53679699a6fd7944d9c4a366b514baf43088e0708b1023dd289702f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+				hex!("02c96e7cb1e8aa5dac64d872947914198f607d90ecde5200de52978ad5ded63c000299ec5117c2d29edee8a2092587c3909be694d5cff0667d6c02ea4059f7cd9786")
+			),
+		];
+
+		for (seckey, pubkey, aggpk, message, extra, secnonce, pubnonce) in cases.iter() {
+			match nonce_gen_internal(&rand, *seckey, pubkey, *aggpk, *message, extra) {
+				Some((s, p)) => {
+					assert_eq!(secnonce_to_bytes(&s)[..], secnonce[..]);
+					assert_eq!(p[..], pubnonce[..]);
+				},
+				None => panic!("nonce_gen failed")
+			}
+		}
+	}
+
+	#[test]
+	fn can_nonce_agg_vectors() {
+		// BIP327 nonce_agg_vectors.json
+		let pnonces: [[u8; 66]; 7] = [
+			hex!("020151c80f435648df67a22b749cd798ce54e0321d034b92b709b567d60a42e66603ba47fbc1834437b3212e89a84d8425e7bf12e0245d98262268ebdcb385d50641"),
+			hex!("03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b833"),
+			hex!("020151c80f435648df67a22b749cd798ce54e0321d034b92b709b567d60a42e6660279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+			hex!("03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+			hex!("04ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b833"),
+			hex!("03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b831"),
+			hex!("03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a602fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30"),
+		];
+		let valid: [(&[usize], [u8; 66]); 2] = [
+			(&[0, 1], hex!("035fe1873b4f2967f52fea4a06ad5a8eccbe9d0fd73068012c894e2e87ccb5804b024725377345bde0e9c33af3c43c0a29a9249f2f2956fa8cfeb55c8573d0262dc8")),
+			(&[2, 3], hex!("035fe1873b4f2967f52fea4a06ad5a8eccbe9d0fd73068012c894e2e87ccb5804b000000000000000000000000000000000000000000000000000000000000000000")),
+		];
+		let invalid: [&[usize]; 3] = [&[0, 4], &[5, 1], &[6, 1]];
+
+		for (indices, expected) in valid.iter() {
+			assert_eq!(ext_musig2_nonce_agg(&concat_indexed(&pnonces, indices))[..], expected[..]);
+		}
+
+		for indices in invalid.iter() {
+			assert_eq!(nonce_agg(&concat_indexed(&pnonces, indices)), Err("Invalid pubnonces provided."));
+		}
+	}
+
+	#[test]
+	fn can_nonce_agg_infinity() {
+		let nonce = [PUBKEYS[0], PUBKEYS[1]].concat();
+		let mut negated = nonce.clone();
+
+		negated[0] = 0x03;
+		negated[33] = 0x02;
+
+		let res = ext_musig2_nonce_agg(&[nonce, negated].concat());
+
+		assert_eq!(res[..], [0u8; 66][..]);
+	}
+
+	#[test]
+	fn can_sign_verify_vectors() {
+		let valid: [SignCase; 4] = [
+			(&[0, 1, 2], &[0, 1, 2], 0, 0, hex!("012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb")),
+			(&[1, 0, 2], &[1, 0, 2], 0, 1, hex!("9ff2f7aaa856150cc8819254218d3adeeb0535269051897724f9db3789513a52")),
+			(&[1, 2, 0], &[1, 2, 0], 0, 2, hex!("fa23c359f6fac4e7796bb93bc9f0532a95468c539ba20ff86d7c76ed92227900")),
+			(&[0, 1], &[0, 3], 1, 0, hex!("ae386064b26105404798f75de2eb9af5eda5387b064b83d049cb7c5e08879531")),
+		];
+
+		for (keys, nonces, aggnonce, signer, expected) in valid.iter() {
+			let mut session = MuSig2Session::new(&concat_indexed(&SV_PUBKEYS, keys)).expect("valid session");
+
+			assert_eq!(nonce_agg(&concat_indexed(&SV_PUBNONCES, nonces)).as_deref(), Ok(&SV_AGGNONCES[*aggnonce][..]));
+
+			session.secnonce = Some(secnonce_from_bytes(&SV_SECNONCE));
+
+			assert_eq!(session.sign(&SV_SECKEY, &SV_AGGNONCES[*aggnonce], &SV_MESSAGE).as_deref(), Ok(&expected[..]));
+			assert!(session.partial_verify(expected, &SV_PUBNONCES[nonces[*signer]], &SV_PUBKEYS[keys[*signer]], &SV_AGGNONCES[*aggnonce], &SV_MESSAGE));
+		}
+	}
+
+	#[test]
+	fn sign_fails_on_vector_errors() {
+		let zero_secnonce: [u8; 97] = hex!("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9");
+		let cases: [(&[usize], usize, [u8; 97], &str); 5] = [
+			(&[1, 2], 0, SV_SECNONCE, "Invalid secret provided."),
+			(&[1, 2, 0], 2, SV_SECNONCE, "Invalid aggnonce provided."),
+			(&[1, 2, 0], 3, SV_SECNONCE, "Invalid aggnonce provided."),
+			(&[1, 2, 0], 4, SV_SECNONCE, "Invalid aggnonce provided."),
+			(&[0, 1, 2], 0, zero_secnonce, "Invalid secnonce provided."),
+		];
+
+		for (keys, aggnonce, secnonce, error) in cases.iter() {
+			let mut session = MuSig2Session::new(&concat_indexed(&SV_PUBKEYS, keys)).expect("valid session");
+
+			session.secnonce = Some(secnonce_from_bytes(secnonce));
+
+			assert_eq!(session.sign(&SV_SECKEY, &SV_AGGNONCES[*aggnonce], &SV_MESSAGE), Err(*error));
+			assert!(session.secnonce.is_none());
+		}
+
+		// the signer key list contains an invalid key
+		assert!(MuSig2Session::new(&concat_indexed(&SV_PUBKEYS, &[1, 0, 3])).is_err());
+	}
+
+	#[test]
+	fn partial_verify_fails_on_vector_errors() {
+		let session = MuSig2Session::new(&concat_indexed(&SV_PUBKEYS, &[0, 1, 2])).expect("valid session");
+		let aggnonce = SV_AGGNONCES[0];
+		let cases: [([u8; 32], usize); 3] = [
+			// wrong signature (which is equal to the negation of a valid one)
+			(hex!("fed54434ad4cfe953fc527dc6a5e5be8f6234907b7c187559557ce87a0541c46"), 0),
+			// wrong signer
+			(hex!("012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb"), 1),
+			// signature exceeds group size
+			(hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"), 0),
+		];
+
+		for (psig, signer) in cases.iter() {
+			assert!(!session.partial_verify(psig, &SV_PUBNONCES[*signer], &SV_PUBKEYS[*signer], &aggnonce, &SV_MESSAGE));
+		}
+
+		// invalid pubnonce, which cannot be aggregated or verified against
+		let psig = hex!("012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb");
+
+		assert!(nonce_agg(&concat_indexed(&SV_PUBNONCES, &[4, 1, 2])).is_err());
+		assert!(!session.partial_verify(&psig, &SV_PUBNONCES[4], &SV_PUBKEYS[0], &aggnonce, &SV_MESSAGE));
+		// invalid pubkey, which cannot be part of a session
+		assert!(MuSig2Session::new(&concat_indexed(&SV_PUBKEYS, &[3, 1, 2])).is_err());
+	}
+
+	#[test]
+	fn can_tweak_vectors() {
+		// BIP327 tweak_vectors.json
+		let pubkeys: [[u8; 33]; 3] = [
+			hex!("03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9"),
+			hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+			hex!("02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+		];
+		let pubnonces: [[u8; 66]; 3] = [SV_PUBNONCES[0], SV_PUBNONCES[1], SV_PUBNONCES[2]];
+		let tweaks: [[u8; 32]; 5] = [
+			hex!("e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb"),
+			hex!("ae2ea797cc0fe72ac5b97b97f3c6957d7e4199a167a58eb08bcaffda70ac0455"),
+			hex!("f52ecbc565b3d8bea2dfd5b75a4f457e54369809322e4120831626f290fa87e0"),
+			hex!("1969ad73cc177fa0b4fced6df1f7bf9907e665fde9ba196a74fed0a3cf5aef9d"),
+			hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+		];
+		let keys = concat_indexed(&pubkeys, &[1, 2, 0]);
+		let aggnonce = SV_AGGNONCES[0];
+		let cases: [(&[Tweak], [u8; 32]); 5] = [
+			(&[(0, true)], hex!("e28a5c66e61e178c2ba19db77b6cf9f7e2f0f56c17918cd13135e60cc848fe91")),
+			(&[(0, false)], hex!("38b0767798252f21bf5702c48028b095428320f73a4b14db1e25de58543d2d2d")),
+			(&[(0, false), (1, true)], hex!("408a0a21c4a0f5dacaf9646ad6eb6fecd7f7a11f03ed1f48dfff2185bc2c2408")),
+			(&[(0, false), (1, false), (2, true), (3, true)], hex!("45abd206e61e3df2ec9e264a6fec8292141a633c28586388235541f9ade75435")),
+			(&[(0, true), (1, false), (2, true), (3, false)], hex!("b255fdcac27b40c7ce7848e2d3b7bf5ea0ed756da81565ac804ccca3e1d5d239")),
+		];
+
+		assert_eq!(nonce_agg(&concat_indexed(&pubnonces, &[1, 2, 0])).as_deref(), Ok(&aggnonce[..]));
+
+		for (tweaked, expected) in cases.iter() {
+			let mut session = MuSig2Session::new(&keys).expect("valid session");
+
+			for (i, xonly) in tweaked.iter() {
+				assert_eq!(session.apply_tweak(&tweaks[*i], *xonly), Ok(()));
+			}
+
+			session.secnonce = Some(secnonce_from_bytes(&SV_SECNONCE));
+
+			assert_eq!(session.sign(&SV_SECKEY, &aggnonce, &SV_MESSAGE).as_deref(), Ok(&expected[..]));
+			assert!(session.partial_verify(expected, &pubnonces[0], &pubkeys[0], &aggnonce, &SV_MESSAGE));
+		}
+
+		// tweak exceeds group size
+		let mut session = MuSig2Session::new(&keys).expect("valid session");
+
+		assert_eq!(session.apply_tweak(&tweaks[4], false), Err("Invalid tweak provided."));
+	}
+
+	#[test]
+	fn can_sig_agg_vectors() {
+		// BIP327 sig_agg_vectors.json
+		let pubkeys: [[u8; 33]; 4] = [
+			hex!("03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9"),
+			hex!("02d2dc6f5df7c56acf38c7fa0ae7a759ae30e19b37359dfde015872324c7ef6e05"),
+			hex!("03c7fb101d97ff930acd0c6760852ef64e69083de0b06ac6335724754bb4b0522c"),
+			hex!("02352433b21e7e05d3b452b81cae566e06d2e003ece16d1074aaba4289e0e3d581"),
+		];
+		let tweaks: [[u8; 32]; 3] = [
+			hex!("b511da492182a91b0ffb9a98020d55f260ae86d7ecbd0399c7383d59a5f2af7c"),
+			hex!("a815fe049ee3c5aab66310477fbc8bcccac2f3395f59f921c364acd78a2f48dc"),
+			hex!("75448a87274b056468b977be06eb1e9f657577b7320b0a3376ea51fd420d18a8"),
+		];
+		let psigs: [[u8; 32]; 9] = [
+			hex!("b15d2cd3c3d22b04dae438ce653f6b4ecf042f42cfded7c41b64aaf9b4af53fb"),
+			hex!("6193d6ac61b354e9105bbdc8937a3454a6d705b6d57322a5a472a02ce99fcb64"),
+			hex!("9a87d3b79ec67228cb97878b76049b15dbd05b8158d17b5b9114d3c226887505"),
+			hex!("66f82ea90923689b855d36c6b7e032fb9970301481b99e01cdb4d6ac7c347a15"),
+			hex!("4f5aee41510848a6447dcd1bbc78457ef69024944c87f40250d3ef2c25d33efe"),
+			hex!("ddef427bbb847cc027beff4edb01038148917832253ebc355fc33f4a8e2fcce4"),
+			hex!("97b890a26c981da8102d3bc294159d171d72810fdf7c6a691def02f0f7af3fdc"),
+			hex!("53fa9e08ba5243cbcb0d797c5ee83bc6728e539eb76c2d0bf0f971ee4e909971"),
+			hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"),
+		];
+		let message = hex!("599c67ea410d005b9da90817cf03ed3b1c868e4da4edf00a5880b0082c237869");
+		let cases: [SigAggCase; 5] = [
+			(
+				&[0, 1],
+				&[],
+				hex!("0341432722c5cd0268d829c702cf0d1cbce57033eed201fd335191385227c3210c03d377f2d258b64aadc0e16f26462323d701d286046a2ea93365656afd9875982b"),
+				&[0, 1],
+				Ok(hex!("041da22223ce65c92c9a0d6c2cac828aaf1eee56304fec371ddf91ebb2b9ef0912f1038025857fedeb3ff696f8b99fa4bb2c5812f6095a2e0004ec99ce18de1e"))
+			),
+			(
+				&[0, 2],
+				&[],
+				hex!("0224afd36c902084058b51b5d36676bba4dc97c775873768e58822f87fe437d792028cb15929099eee2f5dae404cd39357591ba32e9af4e162b8d3e7cb5efe31cb20"),
+				&[2, 3],
+				Ok(hex!("1069b67ec3d2f3c7c08291accb17a9c9b8f2819a52eb5df8726e17e7d6b52e9f01800260a7e9dac450f4be522de4ce12ba91aeaf2b4279219ef74be1d286add9"))
+			),
+			(
+				&[0, 2],
+				&[(0, false)],
+				hex!("0208c5c438c710f4f96a61e9ff3c37758814b8c3ae12bfea0ed2c87ff6954ff186020b1816ea104b4fca2d304d733e0e19cead51303ff6420bfd222335caa402916d"),
+				&[4, 5],
+				Ok(hex!("5c558e1dcade86da0b2f02626a512e30a22cf5255caea7ee32c38e9a71a0e9148ba6c0e6ec7683b64220f0298696f1b878cd47b107b81f7188812d593971e0cc"))
+			),
+			(
+				&[0, 3],
+				&[(0, true), (1, false), (2, true)],
+				hex!("02b5ad07afcd99b6d92cb433fbd2a28fdeb98eae2eb09b6014ef0f8197cd58403302e8616910f9293cf692c49f351db86b25e352901f0e237bafda11f1c1cef29ffd"),
+				&[6, 7],
+				Ok(hex!("839b08820b681dba8daf4cc7b104e8f2638f9388f8d7a555dc17b6e6971d7426ce07bf6ab01f1db50e4e33719295f4094572b79868e440fb3defd3fac1db589e"))
+			),
+			(
+				&[0, 3],
+				&[(0, true), (1, false), (2, true)],
+				hex!("02b5ad07afcd99b6d92cb433fbd2a28fdeb98eae2eb09b6014ef0f8197cd58403302e8616910f9293cf692c49f351db86b25e352901f0e237bafda11f1c1cef29ffd"),
+				&[7, 8],
+				Err("Invalid psigs provided.")
+			),
+		];
+
+		for (keys, tweaked, aggnonce, signers, expected) in cases.iter() {
+			let mut session = MuSig2Session::new(&concat_indexed(&pubkeys, keys)).expect("valid session");
+
+			for (i, xonly) in tweaked.iter() {
+				assert_eq!(session.apply_tweak(&tweaks[*i], *xonly), Ok(()));
+			}
+
+			let res = session.aggregate(&concat_indexed(&psigs, signers), aggnonce, &message);
+
+			match (res, expected) {
+				(Ok(sig), Ok(expected)) => {
+					assert_eq!(sig[..], expected[..]);
+					assert!(ext_secp_schnorr_verify(&sig, &message, &session.agg_pubkey()));
+				},
+				(res, expected) => assert_eq!(res.err(), expected.as_ref().err().copied())
+			}
+		}
+	}
+
+	#[test]
+	fn can_sign_and_aggregate() {
+		let message = hex!("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+		let (seckeys, pubkeys) = create_signers(3);
+		let (handle, aggnonce, rest) = sign_all(&seckeys, &pubkeys, None, &message);
+		let (psigs, pubnonces) = rest.split_at(3 * PSIG_SIZE);
+
+		for i in 0..3 {
+			assert!(ext_musig2_session_partial_verify(
+				handle,
+				&psigs[i * PSIG_SIZE..(i + 1) * PSIG_SIZE],
+				&pubnonces[i * PUBNONCE_SIZE..(i + 1) * PUBNONCE_SIZE],
+				&ext_secp_from_seed(&seckeys[i])[32..],
+				&aggnonce,
+				&message
+			));
+		}
+
+		let sig = ext_musig2_session_aggregate(handle, psigs, &aggnonce, &message);
+
+		assert!(ext_secp_schnorr_verify(&sig, &message, &ext_musig2_session_agg_pubkey(handle)));
+		assert!(ext_musig2_session_free(handle));
+		assert!(!ext_musig2_session_free(handle));
+	}
+
+	#[test]
+	fn can_sign_and_aggregate_tweaked() {
+		let message = hex!("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+		let tweak = hex!("e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb");
+		let (seckeys, pubkeys) = create_signers(2);
+		let (handle, aggnonce, rest) = sign_all(&seckeys, &pubkeys, Some(&tweak), &message);
+		let sig = ext_musig2_session_aggregate(handle, &rest[..2 * PSIG_SIZE], &aggnonce, &message);
+		let untweaked = ext_musig2_session_new(&pubkeys);
+
+		assert!(ext_secp_schnorr_verify(&sig, &message, &ext_musig2_session_agg_pubkey(handle)));
+		assert!(!ext_secp_schnorr_verify(&sig, &message, &ext_musig2_session_agg_pubkey(untweaked)));
+		assert_eq!(ext_musig2_session_agg_pubkey_full(handle)[1..], ext_musig2_session_agg_pubkey(handle)[..]);
+	}
+
+	#[test]
+	fn can_partial_verify_fail() {
+		let message = hex!("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+		let (seckeys, pubkeys) = create_signers(2);
+		let (handle, aggnonce, rest) = sign_all(&seckeys, &pubkeys, None, &message);
+		let (psigs, pubnonces) = rest.split_at(2 * PSIG_SIZE);
+
+		let pubkey0 = ext_secp_from_seed(&seckeys[0])[32..].to_vec();
+		let pubkey1 = ext_secp_from_seed(&seckeys[1])[32..].to_vec();
+
+		assert!(ext_musig2_session_partial_verify(handle, &psigs[..PSIG_SIZE], &pubnonces[..PUBNONCE_SIZE], &pubkey0, &aggnonce, &message));
+		// signature of signer 0 against the nonce and key of signer 1
+		assert!(!ext_musig2_session_partial_verify(handle, &psigs[..PSIG_SIZE], &pubnonces[PUBNONCE_SIZE..], &pubkey1, &aggnonce, &message));
+		// signature of signer 0 against a different message
+		assert!(!ext_musig2_session_partial_verify(handle, &psigs[..PSIG_SIZE], &pubnonces[..PUBNONCE_SIZE], &pubkey0, &aggnonce, &[]));
+	}
+
+	#[test]
+	#[should_panic(expected = "No unused nonce available.")]
+	fn can_reject_nonce_reuse() {
+		let message = hex!("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+		let (seckeys, pubkeys) = create_signers(1);
+		let handle = ext_musig2_session_new(&pubkeys);
+		let pubnonce = ext_musig2_session_nonce_gen(handle, &seckeys[0], &message, &[]);
+		let aggnonce = ext_musig2_nonce_agg(&pubnonce);
+
+		ext_musig2_session_sign(handle, &seckeys[0], &aggnonce, &message);
+		ext_musig2_session_sign(handle, &seckeys[0], &aggnonce, &message);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid handle provided.")]
+	fn fails_on_freed_session() {
+		let (_, pubkeys) = create_signers(2);
+		let handle = ext_musig2_session_new(&pubkeys);
+
+		ext_musig2_session_free(handle);
+		ext_musig2_session_agg_pubkey(handle);
+	}
+}
@@ -1,9 +1,85 @@
 // Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use secp256k1::{ecdsa::{RecoverableSignature, RecoveryId}, Message, PublicKey, SecretKey, SECP256K1};
+use aes_gcm::{aead::{consts::U16, generic_array::GenericArray, AeadInPlace, NewAead}, aes::Aes256, AesGcm};
+use hkdf::Hkdf;
+use secp256k1::{constants::PUBLIC_KEY_SIZE, ecdh::SharedSecret, ffi::{self, CPtr}, ecdsa::{RecoverableSignature, RecoveryId, Signature}, schnorr, KeyPair, Message, Parity, PublicKey, SecretKey, XOnlyPublicKey, SECP256K1};
+use sha2::Sha256;
 use wasm_bindgen::prelude::*;
 
+use crate::hashing::{ext_blake2b, ext_keccak256, tagged_hash};
+
+/// Hasher selection for ext_secp_sign_hashed, the message is the 32-byte hash
+pub const HASHER_NONE: u32 = 0;
+
+/// Hasher selection for ext_secp_sign_hashed, blake2b-256 as per Substrate
+pub const HASHER_BLAKE2: u32 = 1;
+
+/// Hasher selection for ext_secp_sign_hashed, keccak256 as per Ethereum
+pub const HASHER_KECCAK: u32 = 2;
+
+/// AES-256-GCM with the 16-byte nonce used by eciesjs
+type EciesAes = AesGcm<Aes256, U16>;
+
+/// Size of the ECIES ephemeral (uncompressed) public key, bytes
+pub const ECIES_PUBKEY_SIZE: usize = 65;
+
+/// Size of the ECIES AES-GCM nonce, bytes
+pub const ECIES_NONCE_SIZE: usize = 16;
+
+/// Size of the ECIES AES-GCM tag, bytes
+pub const ECIES_TAG_SIZE: usize = 16;
+
+/// Size of the ECIES overhead, ephemeral key, nonce and tag
+pub const ECIES_OVERHEAD_SIZE: usize = ECIES_PUBKEY_SIZE + ECIES_NONCE_SIZE + ECIES_TAG_SIZE;
+
+/// Hash a message for signing with the specified hasher
+fn hash_message(message: &[u8], hasher: u32) -> Option<Message> {
+	match hasher {
+		HASHER_NONE => Message::from_slice(message).ok(),
+		HASHER_BLAKE2 => Message::from_slice(&ext_blake2b(message, &[], 32)).ok(),
+		HASHER_KECCAK => Message::from_slice(&ext_keccak256(message)).ok(),
+		_ => None
+	}
+}
+
+/// RFC6979 recoverable signing, with the optional extra entropy mixed into the nonce
+fn sign_recoverable(message: &Message, seckey: &SecretKey, extra: &[u8]) -> RecoverableSignature {
+	match extra.len() {
+		0 => SECP256K1.sign_ecdsa_recoverable(message, seckey),
+		32 => {
+			let mut sig = ffi::recovery::RecoverableSignature::new();
+
+			// the secp256k1 crate doesn't expose the RFC6979 additional data, so
+			// we call libsecp256k1 directly (the same call made without extra data)
+			let res = unsafe {
+				ffi::recovery::secp256k1_ecdsa_sign_recoverable(
+					*SECP256K1.ctx(),
+					&mut sig,
+					message.as_c_ptr(),
+					seckey.as_c_ptr(),
+					ffi::secp256k1_nonce_function_rfc6979,
+					extra.as_c_ptr() as *const ffi::types::c_void
+				)
+			};
+
+			match res {
+				1 => RecoverableSignature::from(sig),
+				_ => panic!("Unable to sign.")
+			}
+		},
+		_ => panic!("Invalid extra entropy provided.")
+	}
+}
+
+/// BIP341 TapTweak for an internal key and (possibly empty) merkle root
+fn taproot_tweak(internal: &XOnlyPublicKey, merkle_root: &[u8]) -> [u8; 32] {
+	match merkle_root.len() {
+		0 | 32 => tagged_hash(b"TapTweak", &[&internal.serialize(), merkle_root]),
+		_ => panic!("Invalid merkle root provided.")
+	}
+}
+
 #[wasm_bindgen]
 pub fn ext_secp_pub_compress(pubkey: &[u8]) -> Vec<u8> {
 	match PublicKey::from_slice(&pubkey) {
@@ -74,6 +150,515 @@ pub fn ext_secp_sign(hash: &[u8], seckey: &[u8]) -> Vec<u8> {
 	}
 }
 
+/// Parse a compact signature
+///
+/// Accepts either the 64-byte compact form or the 65-byte output of
+/// ext_secp_sign, in which case the trailing recovery byte is ignored.
+fn sig_from_compact(sig: &[u8]) -> Option<Signature> {
+	let sig = match sig.len() {
+		65 => &sig[..64],
+		_ => sig
+	};
+
+	Signature::from_compact(sig).ok()
+}
+
+/// Verify a signature against a hash and public key
+///
+/// libsecp256k1 only accepts signatures with a low S, so when not running in
+/// strict mode the signature is normalized before verification.
+fn verify(hash: &[u8], sig: Option<Signature>, pubkey: &[u8], strict: bool) -> bool {
+	match (Message::from_slice(hash), sig, PublicKey::from_slice(pubkey)) {
+		(Ok(m), Some(mut s), Ok(k)) => {
+			if !strict {
+				s.normalize_s();
+			}
+
+			SECP256K1
+				.verify_ecdsa(&m, &s, &k)
+				.is_ok()
+		},
+		_ => false
+	}
+}
+
+/// Verify a compact signature against a hash and public key
+///
+/// * hash: UIntArray with 32 element
+/// * sig: UIntArray with 64 element (65 when the recovery byte is included)
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * strict: When true, signatures with a high S are rejected
+#[wasm_bindgen]
+pub fn ext_secp_verify(hash: &[u8], sig: &[u8], pubkey: &[u8], strict: bool) -> bool {
+	verify(hash, sig_from_compact(sig), pubkey, strict)
+}
+
+/// Verify a DER-encoded signature against a hash and public key
+///
+/// * hash: UIntArray with 32 element
+/// * sig: Strict DER-encoded signature
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * strict: When true, signatures with a high S are rejected
+#[wasm_bindgen]
+pub fn ext_secp_verify_der(hash: &[u8], sig: &[u8], pubkey: &[u8], strict: bool) -> bool {
+	verify(hash, Signature::from_der(sig).ok(), pubkey, strict)
+}
+
+/// Convert a compact signature to DER
+///
+/// * sig: UIntArray with 64 element (65 when the recovery byte is included)
+///
+/// returned vector is the DER-encoded signature (at most 72 bytes)
+#[wasm_bindgen]
+pub fn ext_secp_sig_to_der(sig: &[u8]) -> Vec<u8> {
+	match sig_from_compact(sig) {
+		Some(s) => s
+			.serialize_der()
+			.to_vec(),
+		_ => panic!("Invalid signature provided.")
+	}
+}
+
+/// Convert a DER signature to the compact form
+///
+/// * sig: Strict DER-encoded signature
+///
+/// returned vector is the compact signature of 64 bytes
+#[wasm_bindgen]
+pub fn ext_secp_sig_from_der(sig: &[u8]) -> Vec<u8> {
+	match Signature::from_der(sig) {
+		Ok(s) => s
+			.serialize_compact()
+			.to_vec(),
+		_ => panic!("Invalid signature provided.")
+	}
+}
+
+/// Normalize a compact signature to have a low S
+///
+/// * sig: UIntArray with 64 element (65 when the recovery byte is included)
+///
+/// returned vector is the normalized compact signature of 64 bytes
+#[wasm_bindgen]
+pub fn ext_secp_sig_normalize(sig: &[u8]) -> Vec<u8> {
+	match sig_from_compact(sig) {
+		Some(mut s) => {
+			s.normalize_s();
+
+			s
+				.serialize_compact()
+				.to_vec()
+		},
+		_ => panic!("Invalid signature provided.")
+	}
+}
+
+/// Convert a public key to the BIP340 x-only form
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+///
+/// returned vector is the x-only public key of 32 bytes
+#[wasm_bindgen]
+pub fn ext_secp_pub_xonly(pubkey: &[u8]) -> Vec<u8> {
+	match PublicKey::from_slice(pubkey) {
+		Ok(p) => XOnlyPublicKey::from(p)
+			.serialize()
+			.to_vec(),
+		_ => panic!("Invalid pubkey provided.")
+	}
+}
+
+/// Create a BIP340 Schnorr signature
+///
+/// * hash: UIntArray with 32 element
+/// * seckey: UIntArray with 32 element
+/// * aux: UIntArray with 32 element of auxiliary randomness (or empty for none)
+///
+/// returned vector is the signature consisting of 64 bytes.
+#[wasm_bindgen]
+pub fn ext_secp_schnorr_sign(hash: &[u8], seckey: &[u8], aux: &[u8]) -> Vec<u8> {
+	match (Message::from_slice(hash), KeyPair::from_seckey_slice(SECP256K1, seckey)) {
+		(Ok(m), Ok(k)) => {
+			let sig = match aux.len() {
+				0 => SECP256K1.sign_schnorr_no_aux_rand(&m, &k),
+				32 => {
+					let mut rand = [0u8; 32];
+
+					rand.copy_from_slice(aux);
+
+					SECP256K1.sign_schnorr_with_aux_rand(&m, &k, &rand)
+				},
+				_ => panic!("Invalid aux provided.")
+			};
+
+			sig
+				.as_ref()
+				.to_vec()
+		},
+		_ => panic!("Invalid message or secret provided.")
+	}
+}
+
+/// Verify a BIP340 Schnorr signature against an x-only public key
+///
+/// * sig: UIntArray with 64 element
+/// * hash: UIntArray with 32 element
+/// * pubkey: UIntArray with 32 element
+#[wasm_bindgen]
+pub fn ext_secp_schnorr_verify(sig: &[u8], hash: &[u8], pubkey: &[u8]) -> bool {
+	match (schnorr::Signature::from_slice(sig), Message::from_slice(hash), XOnlyPublicKey::from_slice(pubkey)) {
+		(Ok(s), Ok(m), Ok(k)) => SECP256K1
+			.verify_schnorr(&s, &m, &k)
+			.is_ok(),
+		_ => false
+	}
+}
+
+/// Calculate the BIP341 taproot output key
+///
+/// * pubkey: UIntArray with 32 element, the x-only internal key
+/// * merkle_root: UIntArray with 32 element (or empty for key-path only outputs)
+///
+/// returned vector is the output key of 33 bytes, with the first byte
+/// indicating the parity as 0x02 (even) or 0x03 (odd)
+#[wasm_bindgen]
+pub fn ext_secp_taproot_tweak_pub(pubkey: &[u8], merkle_root: &[u8]) -> Vec<u8> {
+	match XOnlyPublicKey::from_slice(pubkey) {
+		Ok(mut k) => {
+			let tweak = taproot_tweak(&k, merkle_root);
+
+			match k.tweak_add_assign(SECP256K1, &tweak) {
+				Ok(p) => {
+					let mut res = vec![];
+
+					res.push(match p {
+						Parity::Even => 0x02,
+						Parity::Odd => 0x03
+					});
+					res.extend_from_slice(&k.serialize());
+
+					res
+				},
+				_ => panic!("Invalid tweak produced.")
+			}
+		},
+		_ => panic!("Invalid pubkey provided.")
+	}
+}
+
+/// Calculate the BIP341 tweaked secret key for taproot key-path spends
+///
+/// * seckey: UIntArray with 32 element, the internal secret key
+/// * merkle_root: UIntArray with 32 element (or empty for key-path only outputs)
+///
+/// returned vector is the tweaked secret key of 32 bytes
+#[wasm_bindgen]
+pub fn ext_secp_taproot_tweak_sec(seckey: &[u8], merkle_root: &[u8]) -> Vec<u8> {
+	match KeyPair::from_seckey_slice(SECP256K1, seckey) {
+		Ok(mut k) => {
+			let tweak = taproot_tweak(&k.public_key(), merkle_root);
+
+			match k.tweak_add_assign(SECP256K1, &tweak) {
+				Ok(_) => k
+					.serialize_secret()
+					.to_vec(),
+				_ => panic!("Invalid tweak produced.")
+			}
+		},
+		_ => panic!("Invalid secret provided.")
+	}
+}
+
+/// Derive the ECIES symmetric key, HKDF-SHA256 over the ephemeral key and shared point (pubkey * seckey)
+fn ecies_key(ephemeral: &PublicKey, pubkey: &PublicKey, seckey: &SecretKey) -> [u8; 32] {
+	let mut point = *pubkey;
+	let mut res = [0u8; 32];
+
+	match point.mul_assign(SECP256K1, &seckey[..]) {
+		Ok(_) => {
+			let master = [&ephemeral.serialize_uncompressed()[..], &point.serialize_uncompressed()[..]].concat();
+
+			Hkdf::<Sha256>::new(None, &master)
+				.expand(&[], &mut res)
+				.expect("32 bytes is a valid HKDF-SHA256 length");
+
+			res
+		},
+		_ => panic!("Invalid shared point.")
+	}
+}
+
+/// ECIES encryption with a known ephemeral secret and nonce
+fn ecies_encrypt(pubkey: &[u8], message: &[u8], ephemeral: &[u8], nonce: &[u8]) -> Vec<u8> {
+	match (PublicKey::from_slice(pubkey), SecretKey::from_slice(ephemeral)) {
+		(Ok(k), Ok(e)) => {
+			let ephemeral_pub = PublicKey::from_secret_key(SECP256K1, &e);
+			let key = ecies_key(&ephemeral_pub, &k, &e);
+			let mut data = message.to_vec();
+			let mut res = vec![];
+
+			match EciesAes::new(GenericArray::from_slice(&key)).encrypt_in_place_detached(GenericArray::from_slice(nonce), &[], &mut data) {
+				Ok(tag) => {
+					res.extend_from_slice(&ephemeral_pub.serialize_uncompressed());
+					res.extend_from_slice(nonce);
+					res.extend_from_slice(&tag);
+					res.extend_from_slice(&data);
+
+					res
+				},
+				_ => panic!("Unable to encrypt.")
+			}
+		},
+		_ => panic!("Invalid pubkey provided.")
+	}
+}
+
+/// ECDH key agreement, compatible with the libsecp256k1 default
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * seckey: UIntArray with 32 element
+///
+/// returned vector is the 32-byte sha256 hash of the compressed shared point
+#[wasm_bindgen]
+pub fn ext_secp_ecdh(pubkey: &[u8], seckey: &[u8]) -> Vec<u8> {
+	match (PublicKey::from_slice(pubkey), SecretKey::from_slice(seckey)) {
+		(Ok(k), Ok(s)) => SharedSecret::new(&k, &s)
+			.to_vec(),
+		_ => panic!("Invalid pubkey or secret provided.")
+	}
+}
+
+/// ECDH key agreement, returning the raw x coordinate
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * seckey: UIntArray with 32 element
+///
+/// returned vector is the 32-byte x coordinate of the shared point
+#[wasm_bindgen]
+pub fn ext_secp_ecdh_raw(pubkey: &[u8], seckey: &[u8]) -> Vec<u8> {
+	match (PublicKey::from_slice(pubkey), SecretKey::from_slice(seckey)) {
+		(Ok(k), Ok(s)) => SharedSecret::new_with_hash(&k, &s, |x, _| x.into())
+			.to_vec(),
+		_ => panic!("Invalid pubkey or secret provided.")
+	}
+}
+
+/// ECIES encryption, compatible with eciesjs
+///
+/// The symmetric key is HKDF-SHA256 over the uncompressed ephemeral key and
+/// shared point, with AES-256-GCM using a 16-byte nonce.
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * message: Arbitrary length UIntArray
+///
+/// returned vector is the ephemeral key (65 bytes), nonce (16 bytes), tag (16 bytes)
+/// followed by the ciphertext
+#[wasm_bindgen]
+pub fn ext_secp_ecies_encrypt(pubkey: &[u8], message: &[u8]) -> Vec<u8> {
+	let mut nonce = [0u8; ECIES_NONCE_SIZE];
+	let mut ephemeral = [0u8; 32];
+
+	// an invalid secret has a negligible chance, but loop for completeness
+	while SecretKey::from_slice(&ephemeral).is_err() {
+		getrandom::getrandom(&mut ephemeral).expect("Unable to retrieve randomness.");
+	}
+
+	getrandom::getrandom(&mut nonce).expect("Unable to retrieve randomness.");
+
+	ecies_encrypt(pubkey, message, &ephemeral, &nonce)
+}
+
+/// ECIES decryption, compatible with eciesjs
+///
+/// * seckey: UIntArray with 32 element
+/// * encrypted: Output from ext_secp_ecies_encrypt
+///
+/// returned vector is the decrypted message
+#[wasm_bindgen]
+pub fn ext_secp_ecies_decrypt(seckey: &[u8], encrypted: &[u8]) -> Vec<u8> {
+	if encrypted.len() < ECIES_OVERHEAD_SIZE {
+		panic!("Invalid encrypted data provided.");
+	}
+
+	let (ephemeral, rest) = encrypted.split_at(ECIES_PUBKEY_SIZE);
+	let (nonce, rest) = rest.split_at(ECIES_NONCE_SIZE);
+	let (tag, data) = rest.split_at(ECIES_TAG_SIZE);
+
+	match (PublicKey::from_slice(ephemeral), SecretKey::from_slice(seckey)) {
+		(Ok(e), Ok(s)) => {
+			// the shared point is derived from the ephemeral key and our secret
+			let key = ecies_key(&e, &e, &s);
+			let mut res = data.to_vec();
+
+			match EciesAes::new(GenericArray::from_slice(&key)).decrypt_in_place_detached(GenericArray::from_slice(nonce), &[], &mut res, GenericArray::from_slice(tag)) {
+				Ok(_) => res,
+				_ => panic!("Unable to decrypt.")
+			}
+		},
+		_ => panic!("Invalid ephemeral key or secret provided.")
+	}
+}
+
+/// Tweak-add a secret key, (seckey + tweak) mod n
+///
+/// * seckey: UIntArray with 32 element
+/// * tweak: UIntArray with 32 element
+///
+/// returned vector is the tweaked secret key of 32 bytes, or empty when the
+/// secret or tweak is invalid (out of range, or a zero result)
+#[wasm_bindgen]
+pub fn ext_secp_sec_tweak_add(seckey: &[u8], tweak: &[u8]) -> Vec<u8> {
+	match SecretKey::from_slice(seckey) {
+		Ok(mut s) => match s.add_assign(tweak) {
+			Ok(_) => s.serialize_secret().to_vec(),
+			_ => vec![]
+		},
+		_ => vec![]
+	}
+}
+
+/// Tweak-multiply a secret key, (seckey * tweak) mod n
+///
+/// * seckey: UIntArray with 32 element
+/// * tweak: UIntArray with 32 element
+///
+/// returned vector is the tweaked secret key of 32 bytes, or empty when the
+/// secret or tweak is invalid (zero or out of range)
+#[wasm_bindgen]
+pub fn ext_secp_sec_tweak_mul(seckey: &[u8], tweak: &[u8]) -> Vec<u8> {
+	match SecretKey::from_slice(seckey) {
+		Ok(mut s) => match s.mul_assign(tweak) {
+			Ok(_) => s.serialize_secret().to_vec(),
+			_ => vec![]
+		},
+		_ => vec![]
+	}
+}
+
+/// Negate a secret key, (n - seckey)
+///
+/// * seckey: UIntArray with 32 element
+///
+/// returned vector is the negated secret key of 32 bytes, or empty when the secret is invalid
+#[wasm_bindgen]
+pub fn ext_secp_sec_negate(seckey: &[u8]) -> Vec<u8> {
+	match SecretKey::from_slice(seckey) {
+		Ok(mut s) => {
+			s.negate_assign();
+
+			s.serialize_secret().to_vec()
+		},
+		_ => vec![]
+	}
+}
+
+/// Tweak-add a public key, pubkey + tweak * G
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * tweak: UIntArray with 32 element
+///
+/// returned vector is the tweaked compressed public key of 33 bytes, or empty when
+/// the pubkey or tweak is invalid (out of range, or a result at infinity)
+#[wasm_bindgen]
+pub fn ext_secp_pub_tweak_add(pubkey: &[u8], tweak: &[u8]) -> Vec<u8> {
+	match PublicKey::from_slice(pubkey) {
+		Ok(mut k) => match k.add_exp_assign(SECP256K1, tweak) {
+			Ok(_) => k.serialize().to_vec(),
+			_ => vec![]
+		},
+		_ => vec![]
+	}
+}
+
+/// Tweak-multiply a public key, pubkey * tweak
+///
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+/// * tweak: UIntArray with 32 element
+///
+/// returned vector is the tweaked compressed public key of 33 bytes, or empty when
+/// the pubkey or tweak is invalid (zero or out of range)
+#[wasm_bindgen]
+pub fn ext_secp_pub_tweak_mul(pubkey: &[u8], tweak: &[u8]) -> Vec<u8> {
+	match PublicKey::from_slice(pubkey) {
+		Ok(mut k) => match k.mul_assign(SECP256K1, tweak) {
+			Ok(_) => k.serialize().to_vec(),
+			_ => vec![]
+		},
+		_ => vec![]
+	}
+}
+
+/// Combine (sum) multiple public keys
+///
+/// * pubkeys: Concatenated list of compressed public keys, 33 bytes each
+///
+/// returned vector is the combined compressed public key of 33 bytes, or empty when
+/// any of the pubkeys are invalid or the sum is infinity
+#[wasm_bindgen]
+pub fn ext_secp_pub_combine(pubkeys: &[u8]) -> Vec<u8> {
+	if pubkeys.is_empty() || pubkeys.len() % PUBLIC_KEY_SIZE != 0 {
+		return vec![];
+	}
+
+	match pubkeys.chunks(PUBLIC_KEY_SIZE).map(PublicKey::from_slice).collect::<Result<Vec<_>, _>>() {
+		Ok(keys) => match PublicKey::combine_keys(&keys.iter().collect::<Vec<_>>()) {
+			Ok(k) => k.serialize().to_vec(),
+			_ => vec![]
+		},
+		_ => vec![]
+	}
+}
+
+/// Sign a message, optionally hashing it and adding extra entropy
+///
+/// * message: Arbitrary length UIntArray (32 element when no hasher is used)
+/// * seckey: UIntArray with 32 element
+/// * hasher: 0 for none (pre-hashed), 1 for blake2b-256 (Substrate), 2 for keccak256 (Ethereum)
+/// * extra: UIntArray with 32 element of extra entropy for hedged signing (or empty for none)
+///
+/// * returned vector is the signature consisting of 64 bytes and the recovery byte.
+#[wasm_bindgen]
+pub fn ext_secp_sign_hashed(message: &[u8], seckey: &[u8], hasher: u32, extra: &[u8]) -> Vec<u8> {
+	match (hash_message(message, hasher), SecretKey::from_slice(seckey)) {
+		(Some(m), Ok(s)) => {
+			let mut res = vec![];
+			let (rec, sig) = sign_recoverable(&m, &s, extra)
+				.serialize_compact();
+
+			res.extend_from_slice(&sig);
+			res.push(rec.to_i32() as u8);
+
+			res
+		},
+		_ => panic!("Invalid message, hasher or secret provided.")
+	}
+}
+
+/// Verify a message signed by a Substrate ecdsa pair
+///
+/// As per the runtime, the message is hashed with blake2b-256, the public key
+/// recovered from the signature and compared against the provided key.
+///
+/// * sig: UIntArray with 65 element, the signature and recovery byte
+/// * message: Arbitrary length UIntArray
+/// * pubkey: UIntArray with 33 (compressed) or 65 (uncompressed) element
+#[wasm_bindgen]
+pub fn ext_secp_substrate_verify(sig: &[u8], message: &[u8], pubkey: &[u8]) -> bool {
+	if sig.len() != 65 {
+		return false;
+	}
+
+	match (RecoveryId::from_i32(sig[64] as i32), hash_message(message, HASHER_BLAKE2), PublicKey::from_slice(pubkey)) {
+		(Ok(r), Some(m), Ok(k)) => match RecoverableSignature::from_compact(&sig[..64], r) {
+			Ok(s) => s
+				.recover(&m)
+				.map_or(false, |p| p == k),
+			_ => false
+		},
+		_ => false
+	}
+}
+
 #[cfg(test)]
 pub mod tests {
 	use hex_literal::hex;
@@ -127,4 +712,377 @@ pub mod tests {
 
 		assert_eq!(res[..], expected[..]);
 	}
+
+	#[test]
+	fn can_verify() {
+		let pubkey = hex!("03fd8c74f795ced92064b86191cb2772b1e3a0947740aa0a5a6e379592471fd85b");
+		let msg = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let sig = hex!("df92f73d9f060cefacf187b5414491cb992998ace017fa48839b5cda3e264ba83b105adec9e9872647a7d8bb28855b45e22805aea3d097953cbb1391f671d13e01");
+
+		assert!(ext_secp_verify(&msg, &sig, &pubkey, true));
+		assert!(ext_secp_verify(&msg, &sig[..64], &pubkey, true));
+		assert!(ext_secp_verify(&msg, &sig, &ext_secp_pub_expand(&pubkey), true));
+		assert!(!ext_secp_verify(&msg, &sig[..63], &pubkey, false));
+		assert!(!ext_secp_verify(&msg[..31], &sig, &pubkey, false));
+		assert!(!ext_secp_verify(&[0u8; 32], &sig, &pubkey, false));
+	}
+
+	#[test]
+	fn can_verify_high_s() {
+		let pubkey = hex!("03fd8c74f795ced92064b86191cb2772b1e3a0947740aa0a5a6e379592471fd85b");
+		let msg = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let sig = hex!("df92f73d9f060cefacf187b5414491cb992998ace017fa48839b5cda3e264ba8c4efa521361678d9b8582744d77aa4b8d886d7380b7808a683174afad9c47003");
+		let expected = hex!("df92f73d9f060cefacf187b5414491cb992998ace017fa48839b5cda3e264ba83b105adec9e9872647a7d8bb28855b45e22805aea3d097953cbb1391f671d13e");
+
+		assert!(!ext_secp_verify(&msg, &sig, &pubkey, true));
+		assert!(ext_secp_verify(&msg, &sig, &pubkey, false));
+		assert_eq!(ext_secp_sig_normalize(&sig)[..], expected[..]);
+		assert_eq!(ext_secp_sig_normalize(&expected)[..], expected[..]);
+	}
+
+	#[test]
+	fn can_der_roundtrip() {
+		let pubkey = hex!("03fd8c74f795ced92064b86191cb2772b1e3a0947740aa0a5a6e379592471fd85b");
+		let msg = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let sig = hex!("df92f73d9f060cefacf187b5414491cb992998ace017fa48839b5cda3e264ba83b105adec9e9872647a7d8bb28855b45e22805aea3d097953cbb1391f671d13e");
+		let expected = hex!("3045022100df92f73d9f060cefacf187b5414491cb992998ace017fa48839b5cda3e264ba802203b105adec9e9872647a7d8bb28855b45e22805aea3d097953cbb1391f671d13e");
+		let der = ext_secp_sig_to_der(&sig);
+
+		assert_eq!(der[..], expected[..]);
+		assert_eq!(ext_secp_sig_from_der(&der)[..], sig[..]);
+		assert!(ext_secp_verify_der(&msg, &der, &pubkey, true));
+		assert!(!ext_secp_verify_der(&msg, &der[..70], &pubkey, true));
+	}
+
+	#[test]
+	fn can_pub_xonly() {
+		let pubkey = hex!("04b9dc646dd71118e5f7fda681ad9eca36eb3ee96f344f582fbe7b5bcdebb1307763fe926c273235fd979a134076d00fd1683cbd35868cb485d4a3a640e52184af");
+		let expected = hex!("b9dc646dd71118e5f7fda681ad9eca36eb3ee96f344f582fbe7b5bcdebb13077");
+
+		assert_eq!(ext_secp_pub_xonly(&pubkey)[..], expected[..]);
+		assert_eq!(ext_secp_pub_xonly(&ext_secp_pub_compress(&pubkey))[..], expected[..]);
+	}
+
+	#[test]
+	fn can_schnorr_sign_bip340() {
+		// https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+		let vectors = [
+			(
+				hex!("0000000000000000000000000000000000000000000000000000000000000003"),
+				hex!("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+				hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+				hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+				hex!("e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0")
+			),
+			(
+				hex!("b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef"),
+				hex!("dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+				hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+				hex!("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89"),
+				hex!("6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a")
+			),
+			(
+				hex!("c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9"),
+				hex!("dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8"),
+				hex!("c87aa53824b4d7ae2eb035a2b5bbbccc080e76cdc6d1692c4b0b62d798e6d906"),
+				hex!("7e2d58d8b3bcdf1abadec7829054f90dda9805aab56c77333024b9d0a508b75c"),
+				hex!("5831aaeed7b44bb74e5eab94ba9d4294c49bcf2a60728d8b4c200f50dd313c1bab745879a5ad954a72c45a91c3a51d3c7adea98d82f8481e0e1e03674a6f3fb7")
+			),
+			(
+				hex!("0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710"),
+				hex!("25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517"),
+				hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+				hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+				hex!("7eb0509757e246f19449885651611cb965ecc1a187dd51b64fda1edc9637d5ec97582b9cb13db3933705b32ba982af5af25fd78881ebb32771fc5922efc66ea3")
+			)
+		];
+
+		for (seckey, pubkey, aux, msg, expected) in vectors.iter() {
+			let pair = ext_secp_from_seed(seckey);
+			let sig = ext_secp_schnorr_sign(msg, seckey, aux);
+
+			assert_eq!(ext_secp_pub_xonly(&pair[32..])[..], pubkey[..]);
+			assert_eq!(sig[..], expected[..]);
+			assert!(ext_secp_schnorr_verify(&sig, msg, pubkey));
+		}
+	}
+
+	#[test]
+	fn can_schnorr_verify_bip340() {
+		// https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+		let msg = hex!("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89");
+		let pubkey = hex!("dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659");
+		let invalid = [
+			// public key not on the curve
+			(&hex!("eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34"), hex!("6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e17776969e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b")),
+			// has_even_y(R) is false
+			(&pubkey, hex!("fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a14602975563cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2")),
+			// negated message
+			(&pubkey, hex!("1fa62e331edbc21c394792d2ab1100a7b432b013df3f6ff4f99fcb33e0e1515f28890b3edb6e7189b630448b515ce4f8622a954cfe545735aaea5134fccdb2bd")),
+			// negated s value
+			(&pubkey, hex!("6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769961764b3aa9b2ffcb6ef947b6887a226e8d7c93e00c5ed0c1834ff0d0c2e6da6")),
+			// sG - eP is infinite
+			(&pubkey, hex!("0000000000000000000000000000000000000000000000000000000000000000123dda8328af9c23a94c1feecfd123ba4fb73476f0d594dcb65c6425bd186051")),
+			(&pubkey, hex!("00000000000000000000000000000000000000000000000000000000000000017615fbaf5ae28864013c099742deadb4dba87f11ac6754f93780d5a1837cf197")),
+			// sig[0:32] is not an X coordinate on the curve
+			(&pubkey, hex!("4a298dacae57395a15d0795ddbfd1dcb564da82b0f269bc70a74f8220429ba1d69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b")),
+			// sig[0:32] is equal to field size
+			(&pubkey, hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b")),
+			// sig[32:64] is equal to curve order
+			(&pubkey, hex!("6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")),
+			// public key is not a valid X coordinate because it exceeds the field size
+			(&hex!("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30"), hex!("6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e17776969e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b"))
+		];
+
+		assert!(ext_secp_schnorr_verify(
+			&hex!("00000000000000000000003b78ce563f89a0ed9414f5aa28ad0d96d6795f9c6376afb1548af603b3eb45c9f8207dee1060cb71c04e80f593060b07d28308d7f4"),
+			&hex!("4df3c3f68fcc83b27e9d42c90431a72499f17875c81a599b566c9889b9696703"),
+			&hex!("d69c3509bb99e412e68b0fe8544e72837dfa30746d8be2aa65975f29d22dc7b9")
+		));
+
+		for (pubkey, sig) in invalid.iter() {
+			assert!(!ext_secp_schnorr_verify(sig, &msg, &pubkey[..]));
+		}
+	}
+
+	#[test]
+	fn can_taproot_tweak_pub() {
+		// https://github.com/bitcoin/bips/blob/master/bip-0341/wallet-test-vectors.json
+		let internal = hex!("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d");
+		let expected = hex!("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343");
+
+		assert_eq!(ext_secp_taproot_tweak_pub(&internal, &[])[1..], expected[..]);
+
+		let internal = hex!("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27");
+		let merkle_root = hex!("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21");
+		let expected = hex!("147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3");
+
+		assert_eq!(ext_secp_taproot_tweak_pub(&internal, &merkle_root)[1..], expected[..]);
+	}
+
+	#[test]
+	fn can_taproot_tweak_sec() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let merkle_root = hex!("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21");
+		let msg = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let internal = ext_secp_pub_xonly(&ext_secp_from_seed(&seckey)[32..]);
+
+		for root in [&merkle_root[..], &[]].iter() {
+			let tweaked = ext_secp_taproot_tweak_sec(&seckey, root);
+			let output = ext_secp_taproot_tweak_pub(&internal, root);
+			let sig = ext_secp_schnorr_sign(&msg, &tweaked, &[]);
+
+			assert_eq!(ext_secp_pub_xonly(&ext_secp_from_seed(&tweaked)[32..])[..], output[1..]);
+			assert!(ext_secp_schnorr_verify(&sig, &msg, &output[1..]));
+		}
+	}
+
+	#[test]
+	fn can_ecdh() {
+		let self_seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let other_seckey = hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+		let self_pubkey = ext_secp_from_seed(&self_seckey)[32..].to_vec();
+		let other_pubkey = ext_secp_from_seed(&other_seckey)[32..].to_vec();
+		let shared = ext_secp_ecdh(&other_pubkey, &self_seckey);
+		let raw = ext_secp_ecdh_raw(&other_pubkey, &self_seckey);
+
+		assert_eq!(shared, ext_secp_ecdh(&other_pubkey, &self_seckey));
+		assert_eq!(shared, ext_secp_ecdh(&self_pubkey, &other_seckey));
+		assert_eq!(shared, ext_secp_ecdh(&ext_secp_pub_expand(&self_pubkey), &other_seckey));
+		assert_eq!(raw, ext_secp_ecdh_raw(&self_pubkey, &other_seckey));
+		assert_ne!(shared, raw);
+	}
+
+	#[test]
+	fn can_ecdh_known() {
+		// shared point 2 * 3G = 6G, so the x coordinate and hash are known
+		let two = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+		let three = hex!("0000000000000000000000000000000000000000000000000000000000000003");
+		let six_x = hex!("fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556");
+		let six_pub = ext_secp_from_seed(&hex!("0000000000000000000000000000000000000000000000000000000000000006"))[32..].to_vec();
+		let three_pub = ext_secp_from_seed(&three)[32..].to_vec();
+
+		assert_eq!(ext_secp_ecdh_raw(&three_pub, &two)[..], six_x[..]);
+		assert_eq!(ext_secp_ecdh(&three_pub, &two)[..], crate::hashing::ext_sha256(&six_pub)[..]);
+	}
+
+	#[test]
+	fn can_ecies_roundtrip() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+		let message = b"this is a message";
+		let encrypted = ext_secp_ecies_encrypt(&pubkey, message);
+
+		assert_eq!(encrypted.len(), ECIES_OVERHEAD_SIZE + message.len());
+		assert_eq!(ext_secp_ecies_decrypt(&seckey, &encrypted)[..], message[..]);
+		assert_ne!(ext_secp_ecies_encrypt(&pubkey, message), encrypted);
+		assert_eq!(ext_secp_ecies_decrypt(&seckey, &ext_secp_ecies_encrypt(&pubkey, &[])).len(), 0);
+	}
+
+	#[test]
+	fn can_ecies_known() {
+		// eciesjs default (0.4) layout, ephemeral secret 4c08...2318 & nonce f3e1...1e0f
+		let seckey = hex!("95d3c5e483e9b1d4f5fc8e79b2deaf51362980de62dbb082a9a4257eef653d7d");
+		let ephemeral = hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318");
+		let nonce = hex!("f3e1d2c3b4a5968778695a4b3c2d1e0f");
+		let encrypted = hex!("044e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e47fd35c4215d1edf53e6f83de344615ce719bdb0fd878f6ed76f06dd277956def3e1d2c3b4a5968778695a4b3c2d1e0f81c5da0a11dff7066858fb818a854afc19302e0e2df5f28e387f7eb7ace1");
+		let message = "helloworld🌍".as_bytes();
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+
+		assert_eq!(ext_secp_ecies_decrypt(&seckey, &encrypted)[..], message[..]);
+		assert_eq!(ecies_encrypt(&pubkey, message, &ephemeral, &nonce)[..], encrypted[..]);
+	}
+
+	#[test]
+	fn can_ecies_key_known() {
+		// eciespy/ecies-rs known shared secret, secret 2 against the pubkey 3G
+		let two = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+		let three = hex!("0000000000000000000000000000000000000000000000000000000000000003");
+		let expected = hex!("6f982d63e8590c9d9b5b4c1959ff80315d772edd8f60287c9361d548d5200f82");
+		let two_pub = PublicKey::from_slice(&ext_secp_from_seed(&two)[32..]).unwrap();
+		let three_pub = PublicKey::from_slice(&ext_secp_from_seed(&three)[32..]).unwrap();
+
+		assert_eq!(ecies_key(&two_pub, &three_pub, &SecretKey::from_slice(&two).unwrap()), expected);
+	}
+
+	#[test]
+	#[should_panic(expected = "Unable to decrypt.")]
+	fn can_ecies_decrypt_fail() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+		let mut encrypted = ext_secp_ecies_encrypt(&pubkey, b"this is a message");
+		let last = encrypted.len() - 1;
+
+		encrypted[last] ^= 0x01;
+
+		ext_secp_ecies_decrypt(&seckey, &encrypted);
+	}
+
+	#[test]
+	fn can_tweak_sec() {
+		let one = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let two = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+		let four = hex!("0000000000000000000000000000000000000000000000000000000000000004");
+		let minus_one = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+
+		assert_eq!(ext_secp_sec_tweak_add(&one, &one)[..], two[..]);
+		assert_eq!(ext_secp_sec_tweak_mul(&two, &two)[..], four[..]);
+		assert_eq!(ext_secp_sec_negate(&one)[..], minus_one[..]);
+		assert_eq!(ext_secp_sec_negate(&minus_one)[..], one[..]);
+		assert_eq!(ext_secp_sec_tweak_mul(&minus_one, &minus_one)[..], one[..]);
+	}
+
+	#[test]
+	fn can_tweak_pub() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let tweak = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+
+		assert_eq!(
+			ext_secp_pub_tweak_add(&pubkey, &tweak),
+			ext_secp_from_seed(&ext_secp_sec_tweak_add(&seckey, &tweak))[32..].to_vec()
+		);
+		assert_eq!(
+			ext_secp_pub_tweak_mul(&ext_secp_pub_expand(&pubkey), &tweak),
+			ext_secp_from_seed(&ext_secp_sec_tweak_mul(&seckey, &tweak))[32..].to_vec()
+		);
+	}
+
+	#[test]
+	fn can_combine_pub() {
+		let one = ext_secp_from_seed(&hex!("0000000000000000000000000000000000000000000000000000000000000001"))[32..].to_vec();
+		let two = ext_secp_from_seed(&hex!("0000000000000000000000000000000000000000000000000000000000000002"))[32..].to_vec();
+		let expected = hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9");
+
+		assert_eq!(ext_secp_pub_combine(&[one, two.clone()].concat())[..], expected[..]);
+		assert_eq!(ext_secp_pub_combine(&two), two);
+	}
+
+	#[test]
+	fn tweak_fails_on_invalid_inputs() {
+		let one = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let order = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+		let minus_one = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140");
+		let one_pub = ext_secp_from_seed(&one)[32..].to_vec();
+		let minus_one_pub = ext_secp_from_seed(&minus_one)[32..].to_vec();
+		let cases = [
+			("sec add, tweak out of range", ext_secp_sec_tweak_add(&one, &order)),
+			("sec add, zero result", ext_secp_sec_tweak_add(&one, &minus_one)),
+			("sec add, invalid tweak length", ext_secp_sec_tweak_add(&one, &[1u8; 31])),
+			("sec add, invalid secret", ext_secp_sec_tweak_add(&order, &one)),
+			("sec mul, zero tweak", ext_secp_sec_tweak_mul(&one, &[0u8; 32])),
+			("sec mul, tweak out of range", ext_secp_sec_tweak_mul(&one, &order)),
+			("sec negate, invalid secret", ext_secp_sec_negate(&[0u8; 32])),
+			("pub add, tweak out of range", ext_secp_pub_tweak_add(&one_pub, &order)),
+			("pub add, infinity", ext_secp_pub_tweak_add(&one_pub, &minus_one)),
+			("pub mul, zero tweak", ext_secp_pub_tweak_mul(&one_pub, &[0u8; 32])),
+			("pub mul, invalid pubkey", ext_secp_pub_tweak_mul(&one_pub[1..], &one)),
+			("combine, infinity", ext_secp_pub_combine(&[one_pub.clone(), minus_one_pub].concat())),
+			("combine, invalid length", ext_secp_pub_combine(&one_pub[1..])),
+			("combine, empty", ext_secp_pub_combine(&[]))
+		];
+
+		for (name, res) in cases.iter() {
+			assert!(res.is_empty(), "{} returned a result", name);
+		}
+	}
+
+	#[test]
+	fn can_sign_hashed() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let msg = hex!("68c731589a583d08b70861683b59ce3dd56284cb2f0da5b6cd83e6641dac3aab");
+		let message = b"this is a message";
+
+		assert_eq!(ext_secp_sign_hashed(&msg, &seckey, HASHER_NONE, &[]), ext_secp_sign(&msg, &seckey));
+		assert_eq!(ext_secp_sign_hashed(message, &seckey, HASHER_BLAKE2, &[]), ext_secp_sign(&ext_blake2b(message, &[], 32), &seckey));
+		assert_eq!(ext_secp_sign_hashed(message, &seckey, HASHER_KECCAK, &[]), ext_secp_sign(&ext_keccak256(message), &seckey));
+	}
+
+	#[test]
+	fn can_sign_hashed_extra() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+		let message = b"this is a message";
+		let extra_1 = [1u8; 32];
+		let extra_2 = [2u8; 32];
+		let sig = ext_secp_sign_hashed(message, &seckey, HASHER_BLAKE2, &[]);
+		let sig_1 = ext_secp_sign_hashed(message, &seckey, HASHER_BLAKE2, &extra_1);
+		let sig_2 = ext_secp_sign_hashed(message, &seckey, HASHER_BLAKE2, &extra_2);
+
+		assert_ne!(sig_1, sig);
+		assert_ne!(sig_1, sig_2);
+		assert_eq!(sig_1, ext_secp_sign_hashed(message, &seckey, HASHER_BLAKE2, &extra_1));
+
+		for s in [sig, sig_1, sig_2].iter() {
+			assert!(ext_secp_substrate_verify(s, message, &pubkey));
+			assert!(ext_secp_verify(&ext_blake2b(message, &[], 32), s, &pubkey, true));
+		}
+	}
+
+	#[test]
+	fn can_substrate_sign_known() {
+		// https://github.com/paritytech/substrate/blob/master/primitives/core/src/ecdsa.rs
+		let seed = hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+		let pubkey = hex!("048db55b05db86c0b1786ca49f095d76344c9e6056b2f02701a7e7f3c20aabfd913ebbe148dd17c56551a52952371071a6c604b3f3abe8f2c8fa742158ea6dd7d4");
+		let expected = hex!("3dde91174bd9359027be59a428b8146513df80a2a3c7eda2194f64de04a69ab97b753169e94db6ffd50921a2668a48b94ca11e3d32c1ff19cfe88890aa7e8f3c00");
+		let sig = ext_secp_sign_hashed(b"", &seed, HASHER_BLAKE2, &[]);
+
+		assert_eq!(ext_secp_from_seed(&seed)[32..], ext_secp_pub_compress(&pubkey)[..]);
+		assert_eq!(sig[..], expected[..]);
+		assert!(ext_secp_substrate_verify(&sig, b"", &pubkey));
+		assert!(ext_secp_substrate_verify(&sig, b"", &ext_secp_pub_compress(&pubkey)));
+	}
+
+	#[test]
+	fn can_substrate_verify_fail() {
+		let seckey = hex!("4380de832af797688026ce24f85204d508243f201650c1a134929e5458b7fbae");
+		let pubkey = ext_secp_from_seed(&seckey)[32..].to_vec();
+		let other = ext_secp_from_seed(&hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"))[32..].to_vec();
+		let sig = ext_secp_sign_hashed(b"this is a message", &seckey, HASHER_BLAKE2, &[]);
+
+		assert!(!ext_secp_substrate_verify(&sig, b"this is another message", &pubkey));
+		assert!(!ext_secp_substrate_verify(&sig, b"this is a message", &other));
+		assert!(!ext_secp_substrate_verify(&sig[..64], b"this is a message", &pubkey));
+		assert!(!ext_secp_substrate_verify(&[0u8; 65], b"this is a message", &pubkey));
+	}
 }
@@ -7,13 +7,18 @@
 // which was adpated from the initial https://github.com/paritytech/schnorrkel-js/
 // forked at commit eff430ddc3090f56317c80654208b8298ef7ab3f
 
+use std::cell::RefCell;
 use curve25519_dalek::scalar::Scalar;
+use merlin::Transcript;
 use schnorrkel::{
-	ExpansionMode, Keypair, MiniSecretKey, PublicKey, SecretKey, Signature,
+	ExpansionMode, Keypair, MiniSecretKey, PublicKey, SecretKey, Signature, signing_context,
 	derive::{Derivation, ChainCode, CHAIN_CODE_LENGTH},
+	musig::{aggregate_public_key_from_slice, AggregatePublicKey, Commitment, CommitStage, Cosignature, CosignStage, MuSig, Reveal, RevealStage},
 };
 use wasm_bindgen::prelude::*;
 
+use crate::handles::{self, Handles};
+
 // We must make sure that this is the same as declared in the substrate source code.
 const CTX: &'static [u8] = b"substrate";
 
@@ -122,13 +127,26 @@ pub fn ext_sr_from_pair(pair: &[u8]) -> Vec<u8> {
 /// * returned vector is the signature consisting of 64 bytes.
 #[wasm_bindgen]
 pub fn ext_sr_sign(pubkey: &[u8], secret: &[u8], message: &[u8]) -> Vec<u8> {
+	ext_sr_sign_ctx(pubkey, secret, CTX, message)
+}
+
+/// Sign a message under a specific signing context
+///
+/// * pubkey: UIntArray with 32 element
+/// * private: UIntArray with 64 element
+/// * ctx: Arbitrary length UIntArray, the signing context (ext_sr_sign uses "substrate")
+/// * message: Arbitrary length UIntArray
+///
+/// * returned vector is the signature consisting of 64 bytes.
+#[wasm_bindgen]
+pub fn ext_sr_sign_ctx(pubkey: &[u8], secret: &[u8], ctx: &[u8], message: &[u8]) -> Vec<u8> {
 	match (SecretKey::from_ed25519_bytes(secret), PublicKey::from_bytes(pubkey)) {
 		(Ok(s), Ok(k)) => s
-			.sign_simple(CTX, message, &k)
+			.sign_simple(ctx, message, &k)
 			.to_bytes()
 			.to_vec(),
 		_ => panic!("Invalid secret or pubkey provided.")
-	 }
+	}
 }
 
 /// Verify a message and its corresponding against a public key;
@@ -138,9 +156,179 @@ pub fn ext_sr_sign(pubkey: &[u8], secret: &[u8], message: &[u8]) -> Vec<u8> {
 /// * pubkey: UIntArray with 32 element
 #[wasm_bindgen]
 pub fn ext_sr_verify(signature: &[u8], message: &[u8], pubkey: &[u8]) -> bool {
+	ext_sr_verify_ctx(signature, CTX, message, pubkey)
+}
+
+/// Verify a message signed under a specific signing context against a public key
+///
+/// * signature: UIntArray with 64 element
+/// * ctx: Arbitrary length UIntArray, the signing context
+/// * message: Arbitrary length UIntArray
+/// * pubkey: UIntArray with 32 element
+#[wasm_bindgen]
+pub fn ext_sr_verify_ctx(signature: &[u8], ctx: &[u8], message: &[u8], pubkey: &[u8]) -> bool {
 	match (Signature::from_bytes(signature), PublicKey::from_bytes(pubkey)) {
 		(Ok(s), Ok(k)) => k
-			.verify_simple(CTX, message, &s)
+			.verify_simple(ctx, message, &s)
+			.is_ok(),
+		_ => false
+	}
+}
+
+thread_local! {
+	static LABELS: RefCell<Vec<&'static [u8]>> = RefCell::new(Vec::new());
+	static TRANSCRIPTS: RefCell<Handles<Transcript>> = RefCell::new(Handles::new());
+}
+
+/// Returns a static version of the label
+///
+/// merlin only accepts static labels, so these are interned, leaking a single
+/// allocation for every distinct label used
+fn static_label(label: &[u8]) -> &'static [u8] {
+	LABELS.with(|labels| {
+		let mut labels = labels.borrow_mut();
+
+		match labels.iter().find(|l| l[..] == label[..]) {
+			Some(l) => l,
+			None => {
+				let l: &'static [u8] = Box::leak(label.to_vec().into_boxed_slice());
+
+				labels.push(l);
+
+				l
+			}
+		}
+	})
+}
+
+/// Returns a copy of the transcript behind the handle, for use in signing and verification
+pub(crate) fn get_transcript(handle: u32) -> Transcript {
+	handles::with(&TRANSCRIPTS, handle, |t| t.clone())
+}
+
+/// Decode a u64 passed as 8 little-endian bytes
+pub(crate) fn u64_from_le(bytes: &[u8]) -> Option<u64> {
+	let mut value = [0u8; 8];
+
+	match bytes.len() {
+		8 => {
+			value.copy_from_slice(bytes);
+
+			Some(u64::from_le_bytes(value))
+		},
+		_ => None
+	}
+}
+
+/// Create an empty merlin transcript with the protocol label, for signing and VRF operations
+///
+/// Labels are interned for the lifetime of the module, each distinct label
+/// leaks a single allocation, so only use a fixed set of labels.
+///
+/// * label: Arbitrary length UIntArray
+///
+/// returned value is the transcript handle
+#[wasm_bindgen]
+pub fn ext_sr_transcript_new(label: &[u8]) -> u32 {
+	handles::insert(&TRANSCRIPTS, Transcript::new(static_label(label)))
+}
+
+/// Create a transcript as per schnorrkel signing_context
+///
+/// This mirrors the schnorrkel transcript construction, i.e. signing_context(ctx).bytes(msg)
+/// is ext_sr_transcript_signing_context(ctx) followed by ext_sr_transcript_bytes(msg)
+///
+/// * ctx: Arbitrary length UIntArray
+///
+/// returned value is the transcript handle
+#[wasm_bindgen]
+pub fn ext_sr_transcript_signing_context(ctx: &[u8]) -> u32 {
+	let mut t = Transcript::new(b"SigningContext");
+
+	t.append_message(b"", ctx);
+
+	handles::insert(&TRANSCRIPTS, t)
+}
+
+/// Append the message as per schnorrkel SigningContext::bytes
+///
+/// * transcript: the transcript handle
+/// * message: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_sr_transcript_bytes(transcript: u32, message: &[u8]) {
+	handles::with(&TRANSCRIPTS, transcript, |t| t.append_message(b"sign-bytes", message))
+}
+
+/// Append a labelled message
+///
+/// As with ext_sr_transcript_new, each distinct label leaks a single (interned) allocation.
+///
+/// * transcript: the transcript handle
+/// * label: Arbitrary length UIntArray
+/// * message: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_sr_transcript_append_message(transcript: u32, label: &[u8], message: &[u8]) {
+	let label = static_label(label);
+
+	handles::with(&TRANSCRIPTS, transcript, |t| t.append_message(label, message))
+}
+
+/// Append a labelled u64
+///
+/// As with ext_sr_transcript_new, each distinct label leaks a single (interned) allocation.
+///
+/// * transcript: the transcript handle
+/// * label: Arbitrary length UIntArray
+/// * value: UIntArray with 8 element, the value as little-endian
+#[wasm_bindgen]
+pub fn ext_sr_transcript_append_u64(transcript: u32, label: &[u8], value: &[u8]) {
+	let label = static_label(label);
+	let value = match u64_from_le(value) {
+		Some(v) => v,
+		_ => panic!("Invalid value provided.")
+	};
+
+	handles::with(&TRANSCRIPTS, transcript, |t| t.append_u64(label, value))
+}
+
+/// Release the transcript
+///
+/// * transcript: the transcript handle
+///
+/// returns true when the handle referred to a transcript
+#[wasm_bindgen]
+pub fn ext_sr_transcript_free(transcript: u32) -> bool {
+	handles::remove(&TRANSCRIPTS, transcript).is_some()
+}
+
+/// Sign a transcript
+///
+/// * pubkey: UIntArray with 32 element
+/// * private: UIntArray with 64 element
+/// * transcript: the handle of the transcript to sign, left unchanged
+///
+/// * returned vector is the signature consisting of 64 bytes.
+#[wasm_bindgen]
+pub fn ext_sr_sign_transcript(pubkey: &[u8], secret: &[u8], transcript: u32) -> Vec<u8> {
+	match (SecretKey::from_ed25519_bytes(secret), PublicKey::from_bytes(pubkey)) {
+		(Ok(s), Ok(k)) => s
+			.sign(get_transcript(transcript), &k)
+			.to_bytes()
+			.to_vec(),
+		_ => panic!("Invalid secret or pubkey provided.")
+	}
+}
+
+/// Verify a signed transcript against a public key
+///
+/// * signature: UIntArray with 64 element
+/// * transcript: the handle of the signed transcript, left unchanged
+/// * pubkey: UIntArray with 32 element
+#[wasm_bindgen]
+pub fn ext_sr_verify_transcript(signature: &[u8], transcript: u32, pubkey: &[u8]) -> bool {
+	match (Signature::from_bytes(signature), PublicKey::from_bytes(pubkey)) {
+		(Ok(s), Ok(k)) => k
+			.verify(get_transcript(transcript), &s)
 			.is_ok(),
 		_ => false
 	}
@@ -172,6 +360,302 @@ pub fn ext_sr_agree(pubkey: &[u8], secret: &[u8]) -> Vec<u8> {
 	}
 }
 
+/// The protocol stages of a MuSig session
+enum MuSigStage {
+	Commit(MuSig<Transcript, CommitStage<Keypair>>),
+	Reveal(MuSig<Transcript, RevealStage<Keypair>>),
+	Cosign(MuSig<Transcript, CosignStage>),
+}
+
+/// Split concatenated 32-byte public keys
+fn split_pubkeys(pubkeys: &[u8]) -> Option<Vec<PublicKey>> {
+	if pubkeys.is_empty() || pubkeys.len() % 32 != 0 {
+		return None;
+	}
+
+	pubkeys
+		.chunks(32)
+		.map(|k| PublicKey::from_bytes(k).ok())
+		.collect()
+}
+
+/// Calculate the MuSig aggregate public key
+///
+/// * pubkeys: UIntArray with 32 * n elements, the cosigner public keys (in any order)
+///
+/// returned vector is the aggregate public key of 32 bytes
+#[wasm_bindgen]
+pub fn ext_sr_musig_pubkey(pubkeys: &[u8]) -> Vec<u8> {
+	match split_pubkeys(pubkeys) {
+		Some(mut keys) => match aggregate_public_key_from_slice(&mut keys) {
+			Some(a) => a
+				.public_key()
+				.to_bytes()
+				.to_vec(),
+			_ => panic!("Invalid pubkeys provided.")
+		},
+		_ => panic!("Invalid pubkeys provided.")
+	}
+}
+
+/// The 3-round (commit, reveal, cosign) MuSig signing session of a cosigner
+///
+/// The session moves forward through the stages as the own reveal and cosignature
+/// are requested, the secret nonces never leave it and can't be used twice. The
+/// resulting signature verifies against the aggregate key with ext_sr_verify.
+struct SrMuSigSession {
+	stage: Option<MuSigStage>,
+}
+
+impl SrMuSigSession {
+	/// Take the current stage, the session is unusable when a call fails
+	fn take(&mut self) -> MuSigStage {
+		match self.stage.take() {
+			Some(s) => s,
+			_ => panic!("Invalid session stage.")
+		}
+	}
+
+	/// Create the session for a message, in the commitment stage
+	fn new(pair: &[u8], message: &[u8]) -> SrMuSigSession {
+		match Keypair::from_half_ed25519_bytes(pair) {
+			Ok(p) => SrMuSigSession {
+				stage: Some(MuSigStage::Commit(MuSig::new(p, signing_context(CTX).bytes(message)))),
+			},
+			_ => panic!("Invalid pair provided.")
+		}
+	}
+
+	/// Returns our commitment to send to all other cosigners, 16 bytes
+	fn commitment(&self) -> Vec<u8> {
+		match &self.stage {
+			Some(MuSigStage::Commit(m)) => m
+				.our_commitment().0
+				.to_vec(),
+			_ => panic!("Invalid session stage.")
+		}
+	}
+
+	/// Add the commitment of another cosigner
+	///
+	/// * pubkey: UIntArray with 32 element
+	/// * commitment: UIntArray with 16 element
+	fn add_commitment(&mut self, pubkey: &[u8], commitment: &[u8]) {
+		let mut bytes = [0u8; 16];
+
+		match (&mut self.stage, PublicKey::from_bytes(pubkey), commitment.len()) {
+			(Some(MuSigStage::Commit(m)), Ok(k), 16) => {
+				bytes.copy_from_slice(commitment);
+
+				if m.add_their_commitment(k, Commitment(bytes)).is_err() {
+					panic!("Invalid commitment provided.");
+				}
+			},
+			_ => panic!("Invalid commitment provided.")
+		}
+	}
+
+	/// Move to the reveal stage (once all commitments are added)
+	///
+	/// returned vector is our reveal to send to all other cosigners, 128 bytes
+	fn reveal(&mut self) -> Vec<u8> {
+		let m = match self.take() {
+			MuSigStage::Commit(m) => m.reveal_stage(),
+			_ => panic!("Invalid session stage.")
+		};
+		let reveal = m.our_reveal().0.to_vec();
+
+		self.stage = Some(MuSigStage::Reveal(m));
+
+		reveal
+	}
+
+	/// Add the reveal of another cosigner, checked against the commitment
+	///
+	/// * pubkey: UIntArray with 32 element
+	/// * reveal: UIntArray with 128 element
+	fn add_reveal(&mut self, pubkey: &[u8], reveal: &[u8]) {
+		let mut bytes = [0u8; 128];
+
+		match (&mut self.stage, PublicKey::from_bytes(pubkey), reveal.len()) {
+			(Some(MuSigStage::Reveal(m)), Ok(k), 128) => {
+				bytes.copy_from_slice(reveal);
+
+				if m.add_their_reveal(k, Reveal(bytes)).is_err() {
+					panic!("Invalid reveal provided.");
+				}
+			},
+			_ => panic!("Invalid reveal provided.")
+		}
+	}
+
+	/// Move to the cosign stage (once all reveals are added)
+	///
+	/// returned vector is our cosignature to send to all other cosigners, 32 bytes
+	fn cosign(&mut self) -> Vec<u8> {
+		let m = match self.take() {
+			// all committed cosigners need to reveal, otherwise they are silently dropped
+			MuSigStage::Reveal(m) if m.public_keys(false).count() == m.public_keys(true).count() => m.cosign_stage(),
+			MuSigStage::Reveal(_) => panic!("Missing reveals."),
+			_ => panic!("Invalid session stage.")
+		};
+		let cosignature = m.our_cosignature().0.to_vec();
+
+		self.stage = Some(MuSigStage::Cosign(m));
+
+		cosignature
+	}
+
+	/// Add the cosignature of another cosigner
+	///
+	/// * pubkey: UIntArray with 32 element
+	/// * cosignature: UIntArray with 32 element
+	fn add_cosignature(&mut self, pubkey: &[u8], cosignature: &[u8]) {
+		let mut bytes = [0u8; 32];
+
+		match (&mut self.stage, PublicKey::from_bytes(pubkey), cosignature.len()) {
+			(Some(MuSigStage::Cosign(m)), Ok(k), 32) => {
+				bytes.copy_from_slice(cosignature);
+
+				if m.add_their_cosignature(k, Cosignature(bytes)).is_err() {
+					panic!("Invalid cosignature provided.");
+				}
+			},
+			_ => panic!("Invalid cosignature provided.")
+		}
+	}
+
+	/// Returns the aggregate public key of all cosigners in the session, 32 bytes
+	fn pubkey(&self) -> Vec<u8> {
+		let key = match &self.stage {
+			Some(MuSigStage::Commit(m)) => m.expected_public_key(),
+			Some(MuSigStage::Reveal(m)) => m.expected_public_key(),
+			Some(MuSigStage::Cosign(m)) => m.public_key(),
+			_ => panic!("Invalid session stage.")
+		};
+
+		key
+			.to_bytes()
+			.to_vec()
+	}
+
+	/// Create the aggregate signature (once all cosignatures are added)
+	///
+	/// returned vector is the signature consisting of 64 bytes
+	fn sign(&self) -> Vec<u8> {
+		match &self.stage {
+			Some(MuSigStage::Cosign(m)) => match m.sign() {
+				Some(s) => s
+					.to_bytes()
+					.to_vec(),
+				_ => panic!("Missing cosignatures.")
+			},
+			_ => panic!("Invalid session stage.")
+		}
+	}
+}
+
+thread_local! {
+	static MUSIG_SESSIONS: RefCell<Handles<SrMuSigSession>> = RefCell::new(Handles::new());
+}
+
+/// Create a MuSig session for a message, in the commitment stage
+///
+/// * pair: UIntArray with 96 element, the cosigner keypair
+/// * message: Arbitrary length UIntArray
+///
+/// returned value is the session handle
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_new(pair: &[u8], message: &[u8]) -> u32 {
+	handles::insert(&MUSIG_SESSIONS, SrMuSigSession::new(pair, message))
+}
+
+/// Returns our commitment to send to all other cosigners, 16 bytes
+///
+/// * handle: the session handle
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_commitment(handle: u32) -> Vec<u8> {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.commitment())
+}
+
+/// Add the commitment of another cosigner
+///
+/// * handle: the session handle
+/// * pubkey: UIntArray with 32 element
+/// * commitment: UIntArray with 16 element
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_add_commitment(handle: u32, pubkey: &[u8], commitment: &[u8]) {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.add_commitment(pubkey, commitment))
+}
+
+/// Move to the reveal stage (once all commitments are added)
+///
+/// * handle: the session handle
+///
+/// returned vector is our reveal to send to all other cosigners, 128 bytes
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_reveal(handle: u32) -> Vec<u8> {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.reveal())
+}
+
+/// Add the reveal of another cosigner, checked against the commitment
+///
+/// * handle: the session handle
+/// * pubkey: UIntArray with 32 element
+/// * reveal: UIntArray with 128 element
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_add_reveal(handle: u32, pubkey: &[u8], reveal: &[u8]) {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.add_reveal(pubkey, reveal))
+}
+
+/// Move to the cosign stage (once all reveals are added)
+///
+/// * handle: the session handle
+///
+/// returned vector is our cosignature to send to all other cosigners, 32 bytes
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_cosign(handle: u32) -> Vec<u8> {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.cosign())
+}
+
+/// Add the cosignature of another cosigner
+///
+/// * handle: the session handle
+/// * pubkey: UIntArray with 32 element
+/// * cosignature: UIntArray with 32 element
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_add_cosignature(handle: u32, pubkey: &[u8], cosignature: &[u8]) {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.add_cosignature(pubkey, cosignature))
+}
+
+/// Returns the aggregate public key of all cosigners in the session, 32 bytes
+///
+/// * handle: the session handle
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_pubkey(handle: u32) -> Vec<u8> {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.pubkey())
+}
+
+/// Create the aggregate signature (once all cosignatures are added)
+///
+/// * handle: the session handle
+///
+/// returned vector is the signature consisting of 64 bytes
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_sign(handle: u32) -> Vec<u8> {
+	handles::with(&MUSIG_SESSIONS, handle, |s| s.sign())
+}
+
+/// Release the session (along with the secret nonces)
+///
+/// * handle: the session handle
+///
+/// returns true when the handle referred to a session
+#[wasm_bindgen]
+pub fn ext_sr_musig_session_free(handle: u32) -> bool {
+	handles::remove(&MUSIG_SESSIONS, handle).is_some()
+}
+
 #[cfg(test)]
 pub mod tests {
 	extern crate rand;
@@ -329,4 +813,184 @@ pub mod tests {
 
 		assert_eq!(ext_sr_agree(self_pk, other_sk), ext_sr_agree(other_pk, self_sk));
 	}
+
+
+	#[test]
+	fn can_sign_ctx() {
+		let pair = ext_sr_from_seed(&generate_random_seed());
+		let private = &pair[0..SECRET_KEY_LENGTH];
+		let public = &pair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let message = b"this is a message";
+		let signature = ext_sr_sign_ctx(public, private, b"attestation", message);
+
+		assert!(ext_sr_verify_ctx(&signature, b"attestation", message, public));
+		assert!(!ext_sr_verify_ctx(&signature, CTX, message, public));
+		assert!(!ext_sr_verify(&signature, message, public));
+		assert!(ext_sr_verify_ctx(&ext_sr_sign(public, private, message), CTX, message, public));
+	}
+
+	#[test]
+	fn can_sign_transcript_context() {
+		let pair = ext_sr_from_seed(&generate_random_seed());
+		let private = &pair[0..SECRET_KEY_LENGTH];
+		let public = &pair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let message = b"this is a message";
+		let transcript = ext_sr_transcript_signing_context(CTX);
+
+		ext_sr_transcript_bytes(transcript, message);
+
+		// transcript signatures are compatible with the simple context signatures
+		assert!(ext_sr_verify(&ext_sr_sign_transcript(public, private, transcript), message, public));
+		assert!(ext_sr_verify_transcript(&ext_sr_sign(public, private, message), transcript, public));
+		assert!(ext_sr_transcript_free(transcript));
+	}
+
+	#[test]
+	fn can_sign_transcript_labelled() {
+		let pair = ext_sr_from_seed(&generate_random_seed());
+		let private = &pair[0..SECRET_KEY_LENGTH];
+		let public = &pair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let create = |value: u64| {
+			let t = ext_sr_transcript_new(b"attestation");
+
+			ext_sr_transcript_append_message(t, b"subject", b"this is a subject");
+			ext_sr_transcript_append_u64(t, b"epoch", &value.to_le_bytes());
+
+			t
+		};
+		let signature = ext_sr_sign_transcript(public, private, create(42));
+		let mut expected = Transcript::new(b"attestation");
+
+		expected.append_message(b"subject", b"this is a subject");
+		expected.append_u64(b"epoch", 42);
+
+		assert!(PublicKey::from_bytes(public).unwrap().verify(expected, &Signature::from_bytes(&signature).unwrap()).is_ok());
+		assert!(ext_sr_verify_transcript(&signature, create(42), public));
+		assert!(!ext_sr_verify_transcript(&signature, create(43), public));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid value provided.")]
+	fn transcript_append_u64_fails_on_invalid_value() {
+		ext_sr_transcript_append_u64(ext_sr_transcript_new(b"attestation"), b"epoch", &[42, 0, 0, 0]);
+	}
+
+	fn musig_sign(pairs: &[Vec<u8>], message: &[u8]) -> Vec<u32> {
+		let pubkeys: Vec<&[u8]> = pairs
+			.iter()
+			.map(|p| &p[SECRET_KEY_LENGTH..KEYPAIR_LENGTH])
+			.collect();
+		let sessions: Vec<u32> = pairs
+			.iter()
+			.map(|p| ext_sr_musig_session_new(p, message))
+			.collect();
+		let commitments: Vec<Vec<u8>> = sessions
+			.iter()
+			.map(|s| ext_sr_musig_session_commitment(*s))
+			.collect();
+
+		for (i, s) in sessions.iter().enumerate() {
+			for (j, c) in commitments.iter().enumerate().filter(|(j, _)| *j != i) {
+				ext_sr_musig_session_add_commitment(*s, pubkeys[j], c);
+			}
+		}
+
+		let reveals: Vec<Vec<u8>> = sessions
+			.iter()
+			.map(|s| ext_sr_musig_session_reveal(*s))
+			.collect();
+
+		for (i, s) in sessions.iter().enumerate() {
+			for (j, r) in reveals.iter().enumerate().filter(|(j, _)| *j != i) {
+				ext_sr_musig_session_add_reveal(*s, pubkeys[j], r);
+			}
+		}
+
+		let cosignatures: Vec<Vec<u8>> = sessions
+			.iter()
+			.map(|s| ext_sr_musig_session_cosign(*s))
+			.collect();
+
+		for (i, s) in sessions.iter().enumerate() {
+			for (j, c) in cosignatures.iter().enumerate().filter(|(j, _)| *j != i) {
+				ext_sr_musig_session_add_cosignature(*s, pubkeys[j], c);
+			}
+		}
+
+		sessions
+	}
+
+	#[test]
+	fn can_musig_sign() {
+		let message = b"this is a message";
+		let pairs: Vec<Vec<u8>> = (0..3).map(|_| ext_sr_from_seed(&generate_random_seed())).collect();
+		let pubkeys: Vec<u8> = pairs
+			.iter()
+			.flat_map(|p| p[SECRET_KEY_LENGTH..KEYPAIR_LENGTH].to_vec())
+			.collect();
+		let pubkey = ext_sr_musig_pubkey(&pubkeys);
+		let sessions = musig_sign(&pairs, message);
+
+		for s in sessions.iter() {
+			let signature = ext_sr_musig_session_sign(*s);
+
+			assert_eq!(ext_sr_musig_session_pubkey(*s), pubkey);
+			assert!(ext_sr_verify(&signature, message, &pubkey));
+			assert!(!ext_sr_verify(&signature, b"this is another message", &pubkey));
+			assert!(ext_sr_musig_session_free(*s));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Missing reveals.")]
+	fn can_musig_cosign_fail_missing() {
+		let message = b"this is a message";
+		let pairs: Vec<Vec<u8>> = (0..2).map(|_| ext_sr_from_seed(&generate_random_seed())).collect();
+		let session = ext_sr_musig_session_new(&pairs[0], message);
+		let other = ext_sr_musig_session_new(&pairs[1], message);
+
+		ext_sr_musig_session_add_commitment(session, &pairs[1][SECRET_KEY_LENGTH..KEYPAIR_LENGTH], &ext_sr_musig_session_commitment(other));
+		ext_sr_musig_session_reveal(session);
+		ext_sr_musig_session_cosign(session);
+	}
+
+	#[test]
+	#[should_panic(expected = "Missing cosignatures.")]
+	fn can_musig_sign_fail_missing() {
+		let message = b"this is a message";
+		let pairs: Vec<Vec<u8>> = (0..2).map(|_| ext_sr_from_seed(&generate_random_seed())).collect();
+		let session = ext_sr_musig_session_new(&pairs[0], message);
+		let other = ext_sr_musig_session_new(&pairs[1], message);
+
+		ext_sr_musig_session_add_commitment(session, &pairs[1][SECRET_KEY_LENGTH..KEYPAIR_LENGTH], &ext_sr_musig_session_commitment(other));
+		ext_sr_musig_session_reveal(session);
+		ext_sr_musig_session_add_reveal(session, &pairs[1][SECRET_KEY_LENGTH..KEYPAIR_LENGTH], &ext_sr_musig_session_reveal(other));
+		ext_sr_musig_session_cosign(session);
+		ext_sr_musig_session_sign(session);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid reveal provided.")]
+	fn can_musig_reveal_fail_commitment() {
+		let message = b"this is a message";
+		let pairs: Vec<Vec<u8>> = (0..2).map(|_| ext_sr_from_seed(&generate_random_seed())).collect();
+		let session = ext_sr_musig_session_new(&pairs[0], message);
+		let other = ext_sr_musig_session_new(&pairs[1], message);
+
+		// the commitment doesn't match the later reveal
+		ext_sr_musig_session_add_commitment(session, &pairs[1][SECRET_KEY_LENGTH..KEYPAIR_LENGTH], &[0u8; 16]);
+		ext_sr_musig_session_reveal(session);
+		ext_sr_musig_session_add_reveal(session, &pairs[1][SECRET_KEY_LENGTH..KEYPAIR_LENGTH], &ext_sr_musig_session_reveal(other));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid session stage.")]
+	fn can_musig_cosign_fail_twice() {
+		let pair = ext_sr_from_seed(&generate_random_seed());
+		let session = ext_sr_musig_session_new(&pair, b"this is a message");
+
+		ext_sr_musig_session_reveal(session);
+		ext_sr_musig_session_cosign(session);
+		ext_sr_musig_session_cosign(session);
+	}
 }
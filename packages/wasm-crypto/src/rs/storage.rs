@@ -0,0 +1,157 @@
+// Copyright 2019-2023 @polkadot/wasm-crypto authors & contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use wasm_bindgen::prelude::*;
+
+use crate::hashing::{ext_blake2b, ext_twox};
+
+// The STORAGE_HASHER_* values follow the StorageHasher index in the runtime metadata,
+// so the hasher of a map key can be passed through as-is from the decoded metadata.
+
+/// Blake2_128, blake2b-128 of the key
+pub const STORAGE_HASHER_BLAKE2_128: u32 = 0;
+
+/// Blake2_256, blake2b-256 of the key
+pub const STORAGE_HASHER_BLAKE2_256: u32 = 1;
+
+/// Blake2_128Concat, blake2b-128 of the key followed by the key
+pub const STORAGE_HASHER_BLAKE2_128_CONCAT: u32 = 2;
+
+/// Twox128, xxhash-128 of the key
+pub const STORAGE_HASHER_TWOX_128: u32 = 3;
+
+/// Twox256, xxhash-256 of the key
+pub const STORAGE_HASHER_TWOX_256: u32 = 4;
+
+/// Twox64Concat, xxhash-64 of the key followed by the key
+pub const STORAGE_HASHER_TWOX_64_CONCAT: u32 = 5;
+
+/// Identity, the key as-is
+pub const STORAGE_HASHER_IDENTITY: u32 = 6;
+
+/// Hash the encoded key with the selected StorageHasher
+fn storage_hash(hasher: u32, data: &[u8]) -> Vec<u8> {
+	match hasher {
+		STORAGE_HASHER_BLAKE2_128 => ext_blake2b(data, &[], 16),
+		STORAGE_HASHER_BLAKE2_256 => ext_blake2b(data, &[], 32),
+		STORAGE_HASHER_BLAKE2_128_CONCAT => [ext_blake2b(data, &[], 16).as_slice(), data].concat(),
+		STORAGE_HASHER_TWOX_128 => ext_twox(data, 2),
+		STORAGE_HASHER_TWOX_256 => ext_twox(data, 4),
+		STORAGE_HASHER_TWOX_64_CONCAT => [ext_twox(data, 1).as_slice(), data].concat(),
+		STORAGE_HASHER_IDENTITY => data.to_vec(),
+		_ => panic!("Invalid hasher provided.")
+	}
+}
+
+/// Hash a single SCALE-encoded key with the selected StorageHasher
+///
+/// * hasher: The STORAGE_HASHER_* selection
+/// * data: The SCALE-encoded key
+///
+/// Returns a vector with the hashed key
+#[wasm_bindgen]
+pub fn ext_storage_hash(hasher: u32, data: &[u8]) -> Vec<u8> {
+	storage_hash(hasher, data)
+}
+
+/// Build the full storage key for a pallet item, i.e. twox128(pallet) ++ twox128(item)
+/// followed by each of the hashed map keys
+///
+/// * pallet: The pallet (module prefix) name, e.g. "System"
+/// * item: The storage item name, e.g. "Account"
+/// * hashers: The STORAGE_HASHER_* selection for each of the keys (empty for plain values)
+/// * keys: The concatenated SCALE-encoded keys
+/// * lengths: The byte length of each of the keys in the concatenation
+///
+/// Returns a vector with the storage key
+#[wasm_bindgen]
+pub fn ext_storage_key(pallet: &str, item: &str, hashers: &[u32], keys: &[u8], lengths: &[u32]) -> Vec<u8> {
+	let total = lengths.iter().try_fold(0u32, |total, l| total.checked_add(*l));
+
+	if hashers.len() != lengths.len() || total.map(|t| t as usize) != Some(keys.len()) {
+		panic!("Invalid keys provided.");
+	}
+
+	let mut res = ext_twox(pallet.as_bytes(), 2);
+	let mut offset = 0;
+
+	res.extend_from_slice(&ext_twox(item.as_bytes(), 2));
+
+	for (hasher, length) in hashers.iter().zip(lengths.iter()) {
+		let end = offset + *length as usize;
+
+		res.extend_from_slice(&storage_hash(*hasher, &keys[offset..end]));
+		offset = end;
+	}
+
+	res
+}
+
+#[cfg(test)]
+pub mod tests {
+	use hex_literal::hex;
+	use super::*;
+
+	const ALICE: [u8; 32] = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+
+	#[test]
+	fn can_storage_key_value() {
+		let expected = hex!("f0c365c3cf59d671eb72da0e7a4113c49f1f0515f462cdcf84e0f1d6045dfcbb");
+
+		assert_eq!(ext_storage_key("Timestamp", "Now", &[], &[], &[])[..], expected[..]);
+	}
+
+	#[test]
+	fn can_storage_key_map() {
+		let expected_account = hex!("26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9de1e86a9a8c739864cf3cc5ec2bea59fd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+		let expected_hash = hex!("26aa394eea5630e07c48ae0c9558cef7a44704b568d21667356a5a050c118746b4def25cfda6ef3a00000000");
+
+		assert_eq!(ext_storage_key("System", "Account", &[STORAGE_HASHER_BLAKE2_128_CONCAT], &ALICE, &[32])[..], expected_account[..]);
+		assert_eq!(ext_storage_key("System", "BlockHash", &[STORAGE_HASHER_TWOX_64_CONCAT], &[0u8; 4], &[4])[..], expected_hash[..]);
+	}
+
+	#[test]
+	fn can_storage_key_double_map() {
+		let keys = [&ALICE[..], &[1, 0, 0, 0]].concat();
+		let key = ext_storage_key("Pallet", "Item", &[STORAGE_HASHER_BLAKE2_256, STORAGE_HASHER_IDENTITY], &keys, &[32, 4]);
+
+		assert_eq!(key, [
+			ext_twox(b"Pallet", 2),
+			ext_twox(b"Item", 2),
+			ext_blake2b(&ALICE, &[], 32),
+			vec![1, 0, 0, 0]
+		].concat());
+	}
+
+	#[test]
+	fn can_storage_hash() {
+		let data = b"abc";
+
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_BLAKE2_128, data), ext_blake2b(data, &[], 16));
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_BLAKE2_256, data), ext_blake2b(data, &[], 32));
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_BLAKE2_128_CONCAT, data), [ext_blake2b(data, &[], 16), data.to_vec()].concat());
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_TWOX_128, data), ext_twox(data, 2));
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_TWOX_256, data), ext_twox(data, 4));
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_TWOX_64_CONCAT, data), [ext_twox(data, 1), data.to_vec()].concat());
+		assert_eq!(ext_storage_hash(STORAGE_HASHER_IDENTITY, data), data.to_vec());
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid hasher provided.")]
+	fn storage_hash_fails_on_invalid_hasher() {
+		ext_storage_hash(7, b"abc");
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid keys provided.")]
+	fn storage_key_fails_on_invalid_lengths() {
+		ext_storage_key("System", "Account", &[STORAGE_HASHER_BLAKE2_128_CONCAT], &ALICE, &[31]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid keys provided.")]
+	fn storage_key_fails_on_overflowing_lengths() {
+		// wraps around to 32 on a 32-bit sum, matching the length of the keys
+		ext_storage_key("System", "Account", &[STORAGE_HASHER_IDENTITY, STORAGE_HASHER_IDENTITY], &ALICE, &[u32::MAX, 33]);
+	}
+}
@@ -7,10 +7,14 @@
 // which was adpated from the initial https://github.com/paritytech/schnorrkel-js/
 // forked at commit eff430ddc3090f56317c80654208b8298ef7ab3f
 
+use std::cell::RefCell;
 use merlin::Transcript;
-use schnorrkel::{signing_context, vrf::{VRFOutput, VRFProof}, PublicKey, SecretKey};
+use schnorrkel::{context::{attach_chacharng, SigningTranscript}, signing_context, vrf::{VRFInOut, VRFOutput, VRFProof}, Keypair, PublicKey, SecretKey};
 use wasm_bindgen::prelude::*;
 
+use crate::handles::{self, Handles};
+use crate::sr25519::get_transcript;
+
 /// Size of VRF output, bytes
 pub const OUTPUT_SIZE: usize = 32;
 
@@ -42,6 +46,51 @@ pub fn new_transcript(extra: &[u8]) -> Transcript {
 //
 // https://github.com/w3f/schnorrkel/blob/8fa2ad3e9fbf0b652c724df6a87a4b3c5500f759/src/vrf.rs#L660
 
+/// VRF sign the input transcript with an existing keypair, with the extra signing transcript
+fn vrf_sign_keypair<E: SigningTranscript>(keypair: &Keypair, input: Transcript, extra: E) -> Vec<u8> {
+	let mut res: [u8; RESULT_SIZE] = [0u8; RESULT_SIZE];
+	let (io, proof, _) = keypair.vrf_sign_extra(input, extra);
+
+	res[..OUTPUT_SIZE].copy_from_slice(io.as_output_bytes());
+	res[OUTPUT_SIZE..].copy_from_slice(&proof.to_bytes());
+
+	res.to_vec()
+}
+
+/// VRF sign the input transcript, with the extra message transcript
+pub(crate) fn vrf_sign(secret: &[u8], input: Transcript, extra: &[u8]) -> Vec<u8> {
+	match SecretKey::from_ed25519_bytes(secret) {
+		Ok(s) => vrf_sign_keypair(&s.to_keypair(), input, new_transcript(extra)),
+		_ => panic!("Invalid secret provided.")
+	}
+}
+
+/// VRF sign the input transcript, with the extra message transcript and the proof
+/// nonce derived from the secret, transcripts & supplied randomness only
+pub(crate) fn vrf_sign_deterministic(secret: &[u8], input: Transcript, extra: &[u8], randomness: &[u8]) -> Vec<u8> {
+	let mut seed = [0u8; 32];
+
+	match (SecretKey::from_ed25519_bytes(secret), randomness.len()) {
+		(Ok(s), 32) => {
+			seed.copy_from_slice(randomness);
+
+			vrf_sign_keypair(&s.to_keypair(), input, attach_chacharng(new_transcript(extra), seed))
+		},
+		(Err(_), _) => panic!("Invalid secret provided."),
+		_ => panic!("Invalid randomness provided.")
+	}
+}
+
+/// VRF verify the input transcript, with the extra message transcript
+pub(crate) fn vrf_verify(pubkey: &[u8], input: Transcript, extra: &[u8], out: &[u8]) -> bool {
+	match (PublicKey::from_bytes(pubkey), VRFOutput::from_bytes(&out[..OUTPUT_SIZE]), VRFProof::from_bytes(&out[OUTPUT_SIZE..RESULT_SIZE])) {
+		(Ok(k), Ok(o), Ok(p)) => k
+			.vrf_verify_extra(input, &o, &p, new_transcript(extra))
+			.is_ok(),
+		_ => false,
+	}
+}
+
 /// Run a Random Verifiable Function (VRF) on one single input
 /// (message) transcript, and an extra message transcript,
 /// producing the output signature and corresponding short proof.
@@ -54,20 +103,52 @@ pub fn new_transcript(extra: &[u8]) -> Transcript {
 /// * returned vector is the 32-byte output (signature) and 64-byte proof.
 #[wasm_bindgen]
 pub fn ext_vrf_sign(secret: &[u8], ctx: &[u8], msg: &[u8], extra: &[u8]) -> Vec<u8> {
-	match SecretKey::from_ed25519_bytes(secret) {
-		Ok(s) => {
-			let mut res: [u8; RESULT_SIZE] = [0u8; RESULT_SIZE];
-			let (io, proof, _) = s
-				.to_keypair()
-				.vrf_sign_extra(signing_context(ctx).bytes(msg), new_transcript(extra));
+	vrf_sign(secret, signing_context(ctx).bytes(msg), extra)
+}
 
-			res[..OUTPUT_SIZE].copy_from_slice(io.as_output_bytes());
-			res[OUTPUT_SIZE..].copy_from_slice(&proof.to_bytes());
+/// Run a Random Verifiable Function (VRF) on an input transcript,
+/// and an extra message transcript.
+///
+/// * secret: UIntArray with 64 element
+/// * transcript: the input transcript handle, left unchanged
+/// * extra: Arbitrary length UIntArray
+///
+/// * returned vector is the 32-byte output (signature) and 64-byte proof.
+#[wasm_bindgen]
+pub fn ext_vrf_sign_transcript(secret: &[u8], transcript: u32, extra: &[u8]) -> Vec<u8> {
+	vrf_sign(secret, get_transcript(transcript), extra)
+}
 
-			res.to_vec()
-		},
-		_ => panic!("Invalid secret provided.")
-	}
+/// Run a Random Verifiable Function (VRF) on one single input, as per ext_vrf_sign, with
+/// the proof nonce derived from the secret, transcripts and supplied randomness. The same
+/// inputs & randomness always yield the same output and proof, e.g. for test fixtures.
+/// Supplying fixed (e.g. zero) randomness makes the nonce depend on the secret and
+/// transcripts only, which is safe for a VRF, but not to be used for multi-party protocols.
+///
+/// * secret: UIntArray with 64 element
+/// * context: Arbitrary length UIntArray
+/// * message: Arbitrary length UIntArray
+/// * extra: Arbitrary length UIntArray
+/// * randomness: UIntArray with 32 element
+///
+/// * returned vector is the 32-byte output (signature) and 64-byte proof.
+#[wasm_bindgen]
+pub fn ext_vrf_sign_deterministic(secret: &[u8], ctx: &[u8], msg: &[u8], extra: &[u8], randomness: &[u8]) -> Vec<u8> {
+	vrf_sign_deterministic(secret, signing_context(ctx).bytes(msg), extra, randomness)
+}
+
+/// Run a Random Verifiable Function (VRF) on an input transcript, as per
+/// ext_vrf_sign_transcript, with the proof nonce derived from the supplied randomness.
+///
+/// * secret: UIntArray with 64 element
+/// * transcript: the input transcript handle, left unchanged
+/// * extra: Arbitrary length UIntArray
+/// * randomness: UIntArray with 32 element
+///
+/// * returned vector is the 32-byte output (signature) and 64-byte proof.
+#[wasm_bindgen]
+pub fn ext_vrf_sign_transcript_deterministic(secret: &[u8], transcript: u32, extra: &[u8], randomness: &[u8]) -> Vec<u8> {
+	vrf_sign_deterministic(secret, get_transcript(transcript), extra, randomness)
 }
 
 /// Verify VRF proof for one single input transcript, and an extra message transcript,
@@ -80,11 +161,323 @@ pub fn ext_vrf_sign(secret: &[u8], ctx: &[u8], msg: &[u8], extra: &[u8]) -> Vec<
 /// * out_and_proof: 96-byte output & proof array from the ext_sign function.
 #[wasm_bindgen]
 pub fn ext_vrf_verify(pubkey: &[u8], ctx: &[u8], msg: &[u8], extra: &[u8], out: &[u8]) -> bool {
-	match (PublicKey::from_bytes(pubkey), VRFOutput::from_bytes(&out[..OUTPUT_SIZE]), VRFProof::from_bytes(&out[OUTPUT_SIZE..RESULT_SIZE])) {
-		(Ok(k), Ok(o), Ok(p)) => k
-			.vrf_verify_extra(signing_context(ctx).bytes(msg), &o, &p, new_transcript(extra))
-			.is_ok(),
-		_ => false,
+	vrf_verify(pubkey, signing_context(ctx).bytes(msg), extra, out)
+}
+
+/// Verify VRF proof for an input transcript, and an extra message transcript,
+/// and corresponding output.
+///
+/// * pubkey: UIntArray with 32 element
+/// * transcript: the input transcript handle, left unchanged
+/// * extra: Arbitrary length UIntArray
+/// * out_and_proof: 96-byte output & proof array from the ext_vrf_sign_transcript function.
+#[wasm_bindgen]
+pub fn ext_vrf_verify_transcript(pubkey: &[u8], transcript: u32, extra: &[u8], out: &[u8]) -> bool {
+	vrf_verify(pubkey, get_transcript(transcript), extra, out)
+}
+
+/// A VRF signer for many inputs, holding the keypair across calls
+///
+/// Inputs are queued with push or push_transcript, and then signed together via sign_n,
+/// merging the inputs & outputs so that all outputs share a single proof.
+struct VrfSigner {
+	keypair: Keypair,
+	inputs: Vec<Transcript>,
+}
+
+impl VrfSigner {
+	fn new(secret: &[u8]) -> VrfSigner {
+		match SecretKey::from_ed25519_bytes(secret) {
+			Ok(s) => VrfSigner { keypair: s.to_keypair(), inputs: Vec::new() },
+			_ => panic!("Invalid secret provided.")
+		}
+	}
+
+	fn sign_n(&mut self, extra: &[u8]) -> Vec<u8> {
+		if self.inputs.is_empty() {
+			panic!("No inputs provided.");
+		}
+
+		let (ios, proof, _) = self.keypair.vrfs_sign_extra(self.inputs.drain(..), new_transcript(extra));
+		let mut res = Vec::with_capacity(ios.len() * OUTPUT_SIZE + PROOF_SIZE);
+
+		for io in ios.iter() {
+			res.extend_from_slice(io.as_output_bytes());
+		}
+
+		res.extend_from_slice(&proof.to_bytes());
+
+		res
+	}
+}
+
+/// A VRF verifier for many inputs sharing a single proof
+///
+/// Inputs are queued with push or push_transcript (in the same order as signed), and then
+/// checked in one call via verify_n.
+struct VrfVerifier {
+	pubkey: PublicKey,
+	inputs: Vec<Transcript>,
+}
+
+impl VrfVerifier {
+	fn new(pubkey: &[u8]) -> VrfVerifier {
+		match PublicKey::from_bytes(pubkey) {
+			Ok(pubkey) => VrfVerifier { pubkey, inputs: Vec::new() },
+			_ => panic!("Invalid pubkey provided.")
+		}
+	}
+
+	fn verify_n(&mut self, extra: &[u8], out: &[u8]) -> bool {
+		let inputs: Vec<Transcript> = self.inputs.drain(..).collect();
+
+		if inputs.is_empty() || out.len() != inputs.len() * OUTPUT_SIZE + PROOF_SIZE {
+			return false;
+		}
+
+		let (outs, proof) = out.split_at(inputs.len() * OUTPUT_SIZE);
+		let outs: Option<Vec<VRFOutput>> = outs
+			.chunks(OUTPUT_SIZE)
+			.map(|o| VRFOutput::from_bytes(o).ok())
+			.collect();
+
+		match (outs, VRFProof::from_bytes(proof)) {
+			(Some(o), Ok(p)) => self.pubkey
+				.vrfs_verify_extra(inputs, &o, &p, new_transcript(extra))
+				.is_ok(),
+			_ => false
+		}
+	}
+}
+
+thread_local! {
+	static SIGNERS: RefCell<Handles<VrfSigner>> = RefCell::new(Handles::new());
+	static VERIFIERS: RefCell<Handles<VrfVerifier>> = RefCell::new(Handles::new());
+}
+
+/// Create a VRF signer from the secret key
+///
+/// * secret: UIntArray with 64 element
+///
+/// returned value is the handle of the signer
+#[wasm_bindgen]
+pub fn ext_vrf_signer_new(secret: &[u8]) -> u32 {
+	handles::insert(&SIGNERS, VrfSigner::new(secret))
+}
+
+/// Queue an input on the signer, as per signing_context(ctx).bytes(msg)
+///
+/// * signer: the signer handle
+/// * ctx: Arbitrary length UIntArray
+/// * msg: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_vrf_signer_push(signer: u32, ctx: &[u8], msg: &[u8]) {
+	handles::with(&SIGNERS, signer, |s| s.inputs.push(signing_context(ctx).bytes(msg)))
+}
+
+/// Queue an input transcript on the signer
+///
+/// * signer: the signer handle
+/// * transcript: the input transcript handle, left unchanged
+#[wasm_bindgen]
+pub fn ext_vrf_signer_push_transcript(signer: u32, transcript: u32) {
+	let input = get_transcript(transcript);
+
+	handles::with(&SIGNERS, signer, |s| s.inputs.push(input))
+}
+
+/// Sign a single input, equivalent to ext_vrf_sign without re-creating the keypair
+///
+/// * signer: the signer handle
+/// * ctx: Arbitrary length UIntArray
+/// * msg: Arbitrary length UIntArray
+/// * extra: Arbitrary length UIntArray
+///
+/// * returned vector is the 32-byte output (signature) and 64-byte proof.
+#[wasm_bindgen]
+pub fn ext_vrf_signer_sign(signer: u32, ctx: &[u8], msg: &[u8], extra: &[u8]) -> Vec<u8> {
+	handles::with(&SIGNERS, signer, |s| vrf_sign_keypair(&s.keypair, signing_context(ctx).bytes(msg), new_transcript(extra)))
+}
+
+/// Sign all queued inputs with a single merged proof, clearing the queue
+///
+/// * signer: the signer handle
+/// * extra: Arbitrary length UIntArray
+///
+/// * returned vector is the 32-byte outputs, in queued order, followed by the 64-byte proof.
+/// Each output can be used with ext_vrf_make_bytes and the corresponding input.
+#[wasm_bindgen]
+pub fn ext_vrf_signer_sign_n(signer: u32, extra: &[u8]) -> Vec<u8> {
+	handles::with(&SIGNERS, signer, |s| s.sign_n(extra))
+}
+
+/// Release the signer
+///
+/// * signer: the signer handle
+///
+/// returned true if the handle referred to a live signer
+#[wasm_bindgen]
+pub fn ext_vrf_signer_free(signer: u32) -> bool {
+	handles::remove(&SIGNERS, signer).is_some()
+}
+
+/// Create a VRF verifier for the public key
+///
+/// * pubkey: UIntArray with 32 element
+///
+/// returned value is the handle of the verifier
+#[wasm_bindgen]
+pub fn ext_vrf_verifier_new(pubkey: &[u8]) -> u32 {
+	handles::insert(&VERIFIERS, VrfVerifier::new(pubkey))
+}
+
+/// Queue an input on the verifier, as per signing_context(ctx).bytes(msg)
+///
+/// * verifier: the verifier handle
+/// * ctx: Arbitrary length UIntArray
+/// * msg: Arbitrary length UIntArray
+#[wasm_bindgen]
+pub fn ext_vrf_verifier_push(verifier: u32, ctx: &[u8], msg: &[u8]) {
+	handles::with(&VERIFIERS, verifier, |v| v.inputs.push(signing_context(ctx).bytes(msg)))
+}
+
+/// Queue an input transcript on the verifier
+///
+/// * verifier: the verifier handle
+/// * transcript: the input transcript handle, left unchanged
+#[wasm_bindgen]
+pub fn ext_vrf_verifier_push_transcript(verifier: u32, transcript: u32) {
+	let input = get_transcript(transcript);
+
+	handles::with(&VERIFIERS, verifier, |v| v.inputs.push(input))
+}
+
+/// Verify the outputs & shared proof for all queued inputs, clearing the queue
+///
+/// * verifier: the verifier handle
+/// * extra: Arbitrary length UIntArray
+/// * out: the outputs & proof array from ext_vrf_signer_sign_n
+#[wasm_bindgen]
+pub fn ext_vrf_verifier_verify_n(verifier: u32, extra: &[u8], out: &[u8]) -> bool {
+	handles::with(&VERIFIERS, verifier, |v| v.verify_n(extra, out))
+}
+
+/// Release the verifier
+///
+/// * verifier: the verifier handle
+///
+/// returned true if the handle referred to a live verifier
+#[wasm_bindgen]
+pub fn ext_vrf_verifier_free(verifier: u32) -> bool {
+	handles::remove(&VERIFIERS, verifier).is_some()
+}
+
+/// Attach the input transcript to the VRF output (pre-output)
+pub(crate) fn vrf_inout(pubkey: &[u8], input: Transcript, out: &[u8]) -> Option<VRFInOut> {
+	if out.len() < OUTPUT_SIZE {
+		return None;
+	}
+
+	match (PublicKey::from_bytes(pubkey), VRFOutput::from_bytes(&out[..OUTPUT_SIZE])) {
+		(Ok(k), Ok(o)) => o.attach_input_hash(&k, input).ok(),
+		_ => None
+	}
+}
+
+/// The equivalent of VRFInOut::make_bytes::<[u8; N]>(context), with N as runtime size
+fn make_bytes(inout: &VRFInOut, context: &[u8], size: usize) -> Vec<u8> {
+	let mut t = Transcript::new(b"VRFResult");
+	let mut res = vec![0u8; size];
+
+	t.append_message(b"", context);
+	inout.commit(&mut t);
+	t.challenge_bytes(b"", &mut res);
+
+	res
+}
+
+/// Compare the (little-endian) raw output against the (little-endian) threshold
+///
+/// An invalid threshold length never passes the check
+pub(crate) fn check_threshold(inout: &VRFInOut, context: &[u8], threshold: &[u8]) -> bool {
+	if threshold.len() != THRESHOLD_SIZE {
+		return false;
+	}
+
+	let mut raw = [0u8; RAW_OUTPUT_SIZE];
+	let mut limit = [0u8; THRESHOLD_SIZE];
+
+	raw.copy_from_slice(&make_bytes(inout, context, RAW_OUTPUT_SIZE));
+	limit.copy_from_slice(threshold);
+
+	u128::from_le_bytes(raw) < u128::from_le_bytes(limit)
+}
+
+/// Derive output bytes from the VRF output (pre-output), as per schnorrkel make_bytes
+///
+/// * pubkey: UIntArray with 32 element
+/// * ctx: Arbitrary length UIntArray, the signing context of the input
+/// * msg: Arbitrary length UIntArray, the message of the input
+/// * out: 32-byte output (or the 96-byte output & proof) from the ext_vrf_sign function
+/// * context: Arbitrary length UIntArray, the make_bytes context, e.g. "substrate-babe-vrf"
+/// * size: the number of bytes to return
+#[wasm_bindgen]
+pub fn ext_vrf_make_bytes(pubkey: &[u8], ctx: &[u8], msg: &[u8], out: &[u8], context: &[u8], size: u32) -> Vec<u8> {
+	match vrf_inout(pubkey, signing_context(ctx).bytes(msg), out) {
+		// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+		Some(inout) => make_bytes(&inout, context, size as usize),
+		_ => panic!("Invalid pubkey or output provided.")
+	}
+}
+
+/// Derive output bytes from the VRF output (pre-output) of an input transcript
+///
+/// * pubkey: UIntArray with 32 element
+/// * transcript: the input transcript handle, left unchanged
+/// * out: 32-byte output (or the 96-byte output & proof) from the ext_vrf_sign_transcript function
+/// * context: Arbitrary length UIntArray, the make_bytes context
+/// * size: the number of bytes to return
+#[wasm_bindgen]
+pub fn ext_vrf_make_bytes_transcript(pubkey: &[u8], transcript: u32, out: &[u8], context: &[u8], size: u32) -> Vec<u8> {
+	match vrf_inout(pubkey, get_transcript(transcript), out) {
+		// we cast to usize here - due to the WASM, we'd rather have u32 inputs
+		Some(inout) => make_bytes(&inout, context, size as usize),
+		_ => panic!("Invalid pubkey or output provided.")
+	}
+}
+
+/// Check that the 16-byte make_bytes value is below the threshold, e.g. for BABE primary slots
+///
+/// * pubkey: UIntArray with 32 element
+/// * ctx: Arbitrary length UIntArray, the signing context of the input
+/// * msg: Arbitrary length UIntArray, the message of the input
+/// * out: 32-byte output (or the 96-byte output & proof) from the ext_vrf_sign function
+/// * context: Arbitrary length UIntArray, the make_bytes context
+/// * threshold: UIntArray with 16 element, the u128 threshold as little-endian
+///
+/// Returns false when any of the inputs are invalid
+#[wasm_bindgen]
+pub fn ext_vrf_check_threshold(pubkey: &[u8], ctx: &[u8], msg: &[u8], out: &[u8], context: &[u8], threshold: &[u8]) -> bool {
+	match vrf_inout(pubkey, signing_context(ctx).bytes(msg), out) {
+		Some(inout) => check_threshold(&inout, context, threshold),
+		_ => false
+	}
+}
+
+/// Check that the 16-byte make_bytes value of an input transcript is below the threshold
+///
+/// * pubkey: UIntArray with 32 element
+/// * transcript: the input transcript handle, left unchanged
+/// * out: 32-byte output (or the 96-byte output & proof) from the ext_vrf_sign_transcript function
+/// * context: Arbitrary length UIntArray, the make_bytes context
+/// * threshold: UIntArray with 16 element, the u128 threshold as little-endian
+///
+/// Returns false when any of the inputs are invalid
+#[wasm_bindgen]
+pub fn ext_vrf_check_threshold_transcript(pubkey: &[u8], transcript: u32, out: &[u8], context: &[u8], threshold: &[u8]) -> bool {
+	match vrf_inout(pubkey, get_transcript(transcript), out) {
+		Some(inout) => check_threshold(&inout, context, threshold),
+		_ => false
 	}
 }
 
@@ -94,7 +487,7 @@ pub mod tests {
 	extern crate schnorrkel;
 
 	use super::*;
-	use crate::sr25519::ext_sr_from_seed;
+	use crate::sr25519::{ext_sr_from_seed, ext_sr_transcript_append_message, ext_sr_transcript_bytes, ext_sr_transcript_signing_context};
 	use schnorrkel::{KEYPAIR_LENGTH, SECRET_KEY_LENGTH};
 
 	fn generate_random_seed() -> Vec<u8> {
@@ -141,4 +534,210 @@ pub mod tests {
 			&out2
 		));
 	}
+
+	#[test]
+	fn sign_transcript_and_verify() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let message = b"this is a message";
+		let extra = b"this is an extra";
+		let transcript = ext_sr_transcript_signing_context(context);
+
+		ext_sr_transcript_bytes(transcript, message);
+
+		let out = ext_vrf_sign_transcript(private, transcript, extra);
+
+		// transcripts are compatible with the context & message inputs
+		assert_eq!(&out[..OUTPUT_SIZE], &ext_vrf_sign(private, context, message, extra)[..OUTPUT_SIZE]);
+		assert!(ext_vrf_verify(public, context, message, extra, &out));
+		assert!(ext_vrf_verify_transcript(public, transcript, extra, &out));
+
+		ext_sr_transcript_append_message(transcript, b"slot", b"this is a slot");
+
+		assert!(!ext_vrf_verify_transcript(public, transcript, extra, &out));
+	}
+
+	#[test]
+	fn can_make_bytes() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let message = b"this is a message";
+		let out = ext_vrf_sign(private, context, message, &[]);
+		let (io, _, _) = SecretKey::from_ed25519_bytes(private)
+			.unwrap()
+			.to_keypair()
+			.vrf_sign(signing_context(context).bytes(message));
+		let transcript = ext_sr_transcript_signing_context(context);
+
+		ext_sr_transcript_bytes(transcript, message);
+
+		assert_eq!(ext_vrf_make_bytes(public, context, message, &out, b"substrate-babe-vrf", RAW_OUTPUT_SIZE as u32)[..], io.make_bytes::<[u8; RAW_OUTPUT_SIZE]>(b"substrate-babe-vrf")[..]);
+		assert_eq!(ext_vrf_make_bytes(public, context, message, &out[..OUTPUT_SIZE], b"other", 32)[..], io.make_bytes::<[u8; 32]>(b"other")[..]);
+		assert_eq!(ext_vrf_make_bytes_transcript(public, transcript, &out, b"other", 32)[..], io.make_bytes::<[u8; 32]>(b"other")[..]);
+	}
+
+	#[test]
+	fn can_check_threshold() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let message = b"this is a message";
+		let out = ext_vrf_sign(private, context, message, &[]);
+		let raw = ext_vrf_make_bytes(public, context, message, &out, b"substrate-babe-vrf", RAW_OUTPUT_SIZE as u32);
+		let mut value = [0u8; RAW_OUTPUT_SIZE];
+
+		value.copy_from_slice(&raw);
+
+		let value = u128::from_le_bytes(value);
+
+		assert!(ext_vrf_check_threshold(public, context, message, &out, b"substrate-babe-vrf", &(value + 1).to_le_bytes()));
+		assert!(!ext_vrf_check_threshold(public, context, message, &out, b"substrate-babe-vrf", &value.to_le_bytes()));
+		assert!(!ext_vrf_check_threshold(public, context, message, &out, b"substrate-babe-vrf", &[0u8; THRESHOLD_SIZE]));
+		assert!(!ext_vrf_check_threshold(public, context, message, &out, b"substrate-babe-vrf", &[0xffu8; THRESHOLD_SIZE - 1]));
+		assert!(!ext_vrf_check_threshold(public, context, message, &out, b"substrate-babe-vrf", &[]));
+		assert_ne!(ext_vrf_make_bytes(public, context, b"this is another message", &out, b"substrate-babe-vrf", RAW_OUTPUT_SIZE as u32), raw);
+	}
+
+	#[test]
+	fn sign_n_and_verify_n() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let messages: Vec<Vec<u8>> = (0..10u8).map(|i| vec![i; i as usize]).collect();
+		let signer = ext_vrf_signer_new(private);
+		let verifier = ext_vrf_verifier_new(public);
+		let transcript = ext_sr_transcript_signing_context(context);
+
+		ext_sr_transcript_bytes(transcript, b"this is a message");
+
+		for m in messages.iter() {
+			ext_vrf_signer_push(signer, context, m);
+			ext_vrf_verifier_push(verifier, context, m);
+		}
+
+		ext_vrf_signer_push_transcript(signer, transcript);
+		ext_vrf_verifier_push_transcript(verifier, transcript);
+
+		let out = ext_vrf_signer_sign_n(signer, b"extra");
+
+		assert_eq!(out.len(), 11 * OUTPUT_SIZE + PROOF_SIZE);
+		assert!(ext_vrf_verifier_verify_n(verifier, b"extra", &out));
+
+		// outputs match the single-input outputs
+		for (i, m) in messages.iter().enumerate() {
+			assert_eq!(&out[i * OUTPUT_SIZE..(i + 1) * OUTPUT_SIZE], &ext_vrf_sign(private, context, m, &[])[..OUTPUT_SIZE]);
+		}
+
+		assert_eq!(&out[10 * OUTPUT_SIZE..11 * OUTPUT_SIZE], &ext_vrf_sign(private, context, b"this is a message", &[])[..OUTPUT_SIZE]);
+
+		// the queue is cleared after verification
+		assert!(!ext_vrf_verifier_verify_n(verifier, b"extra", &out));
+		assert!(ext_vrf_signer_free(signer));
+		assert!(ext_vrf_verifier_free(verifier));
+		assert!(!ext_vrf_verifier_free(verifier));
+	}
+
+	#[test]
+	fn verify_n_fails_on_mismatch() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let signer = ext_vrf_signer_new(private);
+		let verifier = ext_vrf_verifier_new(public);
+
+		ext_vrf_signer_push(signer, context, b"one");
+		ext_vrf_signer_push(signer, context, b"two");
+
+		let out = ext_vrf_signer_sign_n(signer, &[]);
+
+		// order matters
+		ext_vrf_verifier_push(verifier, context, b"two");
+		ext_vrf_verifier_push(verifier, context, b"one");
+		assert!(!ext_vrf_verifier_verify_n(verifier, &[], &out));
+
+		// extra is part of the proof
+		ext_vrf_verifier_push(verifier, context, b"one");
+		ext_vrf_verifier_push(verifier, context, b"two");
+		assert!(!ext_vrf_verifier_verify_n(verifier, b"extra", &out));
+
+		// too few inputs
+		ext_vrf_verifier_push(verifier, context, b"one");
+		assert!(!ext_vrf_verifier_verify_n(verifier, &[], &out));
+
+		ext_vrf_verifier_push(verifier, context, b"one");
+		ext_vrf_verifier_push(verifier, context, b"two");
+		assert!(ext_vrf_verifier_verify_n(verifier, &[], &out));
+	}
+
+	#[test]
+	fn signer_sign_and_verify() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let signer = ext_vrf_signer_new(private);
+		let out = ext_vrf_signer_sign(signer, b"my VRF context", b"this is a message", b"extra");
+
+		assert!(ext_vrf_verify(public, b"my VRF context", b"this is a message", b"extra", &out));
+	}
+
+	#[test]
+	#[should_panic(expected = "No inputs provided.")]
+	fn sign_n_fails_without_inputs() {
+		let keypair = ext_sr_from_seed(&generate_random_seed());
+
+		ext_vrf_signer_sign_n(ext_vrf_signer_new(&keypair[0..SECRET_KEY_LENGTH]), &[]);
+	}
+
+	#[test]
+	fn sign_deterministic_and_verify() {
+		let seed = generate_random_seed();
+		let keypair = ext_sr_from_seed(seed.as_slice());
+		let private = &keypair[0..SECRET_KEY_LENGTH];
+		let public = &keypair[SECRET_KEY_LENGTH..KEYPAIR_LENGTH];
+		let context = b"my VRF context";
+		let message = b"this is a message";
+		let extra = b"this is an extra";
+		let out1 = ext_vrf_sign_deterministic(private, context, message, extra, &[0u8; 32]);
+		let out2 = ext_vrf_sign_deterministic(private, context, message, extra, &[0u8; 32]);
+		let out3 = ext_vrf_sign_deterministic(private, context, message, extra, &[1u8; 32]);
+		let transcript = ext_sr_transcript_signing_context(context);
+
+		ext_sr_transcript_bytes(transcript, message);
+
+		// the full output & proof is reproducible
+		assert_eq!(out1.len(), RESULT_SIZE);
+		assert_eq!(out1, out2);
+		assert_eq!(out1, ext_vrf_sign_transcript_deterministic(private, transcript, extra, &[0u8; 32]));
+
+		// other randomness only changes the proof
+		assert_eq!(&out1[..OUTPUT_SIZE], &out3[..OUTPUT_SIZE]);
+		assert_ne!(&out1[OUTPUT_SIZE..], &out3[OUTPUT_SIZE..]);
+		assert_eq!(&out1[..OUTPUT_SIZE], &ext_vrf_sign(private, context, message, extra)[..OUTPUT_SIZE]);
+
+		// and the proofs verify as normal
+		assert!(ext_vrf_verify(public, context, message, extra, &out1));
+		assert!(ext_vrf_verify(public, context, message, extra, &out3));
+		assert!(!ext_vrf_verify(public, context, message, b"other", &out1));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid randomness provided.")]
+	fn sign_deterministic_fails_on_invalid_randomness() {
+		let keypair = ext_sr_from_seed(&generate_random_seed());
+
+		ext_vrf_sign_deterministic(&keypair[0..SECRET_KEY_LENGTH], b"ctx", b"msg", &[], &[0u8; 16]);
+	}
 }